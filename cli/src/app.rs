@@ -0,0 +1,124 @@
+//! 命令共用的上下文：RPC、签名 keypair、金库地址，以及交易提交与输出。
+
+use std::path::PathBuf;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::AccountDeserialize;
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022;
+use anyhow::{anyhow, bail, Context, Result};
+use serde_json::{json, Value};
+use solana_hash::Hash;
+use solana_keypair::{read_keypair_file, Keypair};
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+use transfer_contract_client::events::parse_logs;
+use transfer_contract_client::Vault;
+
+use crate::rpc::{cluster_url, Account, RpcClient};
+use crate::GlobalArgs;
+
+/// dry-run 时 replaceRecentBlockhash 会替换掉它，省一次 RPC
+const DRY_RUN_BLOCKHASH: Hash = Hash::new_from_array([0; 32]);
+
+pub struct App {
+    pub rpc: RpcClient,
+    pub vault: Vault,
+    payer: Keypair,
+    dry_run: bool,
+    json: bool,
+}
+
+impl App {
+    pub fn new(args: &GlobalArgs) -> Result<Self> {
+        let path = match &args.keypair {
+            Some(path) => path.clone(),
+            None => {
+                let home = std::env::var_os("HOME").ok_or_else(|| anyhow!("HOME is not set, pass --keypair"))?;
+                PathBuf::from(home).join(".config/solana/id.json")
+            }
+        };
+        let payer = read_keypair_file(&path).map_err(|e| anyhow!("failed to read keypair {}: {e}", path.display()))?;
+        Ok(Self {
+            rpc: RpcClient::new(cluster_url(&args.url)),
+            vault: Vault::new(args.vault_id),
+            payer,
+            dry_run: args.dry_run,
+            json: args.json,
+        })
+    }
+
+    pub fn payer(&self) -> Pubkey {
+        self.payer.pubkey()
+    }
+
+    pub fn account(&self, address: &Pubkey, what: &str) -> Result<Account> {
+        self.rpc.get_account(address)?.ok_or_else(|| anyhow!("{what} {address} does not exist"))
+    }
+
+    /// 读取并反序列化本程序的账户
+    pub fn fetch<T: AccountDeserialize>(&self, address: &Pubkey, what: &str) -> Result<T> {
+        let account = self.account(address, what)?;
+        T::try_deserialize(&mut account.data.as_slice()).with_context(|| format!("failed to decode {what} {address}"))
+    }
+
+    /// mint 所属的 token program（SPL Token 或 Token-2022）
+    pub fn token_program(&self, mint: &Pubkey) -> Result<Pubkey> {
+        let owner = self.account(mint, "mint")?.owner;
+        if owner != spl_token::ID && owner != spl_token_2022::ID {
+            bail!("mint {mint} is owned by {owner}, not a token program");
+        }
+        Ok(owner)
+    }
+
+    /// 由 keypair 签名并发送；dry-run 时只模拟，模拟失败返回错误
+    pub fn submit(&self, instructions: &[Instruction]) -> Result<()> {
+        let payer = self.payer();
+        if self.dry_run {
+            let tx = Transaction::new_signed_with_payer(instructions, Some(&payer), &[&self.payer], DRY_RUN_BLOCKHASH);
+            let simulation = self.rpc.simulate(&tx)?;
+            let events: Vec<&str> = parse_logs(&simulation.logs).iter().map(|e| e.name()).collect();
+            self.print(json!({
+                "dry_run": true,
+                "err": simulation.err,
+                "units_consumed": simulation.units_consumed,
+                "events": events,
+                "logs": simulation.logs,
+            }));
+            if !simulation.err.is_null() {
+                bail!("simulation failed: {}", simulation.err);
+            }
+            return Ok(());
+        }
+        let blockhash = self.rpc.latest_blockhash()?;
+        let tx = Transaction::new_signed_with_payer(instructions, Some(&payer), &[&self.payer], blockhash);
+        let signature = self.rpc.send_and_confirm(&tx)?;
+        self.print(json!({ "signature": signature }));
+        Ok(())
+    }
+
+    /// `--json` 时原样输出；否则每个字段一行
+    pub fn print(&self, value: Value) {
+        if self.json {
+            println!("{value}");
+            return;
+        }
+        let Value::Object(fields) = value else {
+            println!("{value}");
+            return;
+        };
+        for (key, value) in fields {
+            match value {
+                Value::String(s) => println!("{key}: {s}"),
+                Value::Array(items) if items.iter().all(Value::is_string) => {
+                    println!("{key}:");
+                    for item in items {
+                        println!("  {}", item.as_str().unwrap_or_default());
+                    }
+                }
+                other => println!("{key}: {other}"),
+            }
+        }
+    }
+}
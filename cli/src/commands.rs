@@ -0,0 +1,173 @@
+//! 各子命令：读取需要的链上状态，用 [`Vault`](transfer_contract_client::Vault) 构造指令后提交。
+
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::associated_token::spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+use anyhow::{anyhow, Result};
+use serde_json::json;
+use transfer_contract::instructions::deposit_from_user::DepositParams;
+use transfer_contract::state::MintConfigParams;
+use transfer_contract_client::accounts::{decode_mint, decode_token_account, Config, LpPosition, Route};
+use transfer_contract_client::layerzero::{peer_address, LayerZeroAccounts, DEFAULT_SEND_LIBRARY};
+
+use crate::app::App;
+use crate::{Command, MintParams};
+
+pub fn run(app: &App, command: Command) -> Result<()> {
+    let vault = &app.vault;
+    let payer = app.payer();
+    match command {
+        Command::Init { allowed_caller } => app.submit(&[vault.initialize(payer, allowed_caller.unwrap_or(payer))]),
+        Command::SetAllowedCaller { new_allowed_caller } => {
+            app.submit(&[vault.set_allowed_caller(payer, new_allowed_caller)])
+        }
+        Command::SetLayerzeroPrograms { oapp_program, endpoint_program } => {
+            app.submit(&[vault.set_layerzero_programs(payer, oapp_program, endpoint_program)])
+        }
+        Command::RefreshRoutePeer { dst_eid } => app.submit(&[vault.refresh_route_peer(payer, dst_eid)]),
+        Command::AddMint { mint, params } => {
+            let token_program = app.token_program(&mint)?;
+            app.submit(&[
+                vault.add_allowed_mint(payer, mint, token_program, params.into()),
+                create_associated_token_account_idempotent(&payer, &vault.vault_authority, &mint, &token_program),
+            ])
+        }
+        Command::Deposit {
+            mint,
+            amount,
+            dst_eid,
+            dst_token,
+            merchant,
+            options,
+            send_library,
+            native_fee,
+            lz_token_fee,
+            lz_token_mint,
+        } => {
+            let token_program = app.token_program(&mint)?;
+            let config: Config = app.fetch(&vault.config, "config")?;
+            let route: Route = app.fetch(&vault.route(dst_eid), "route")?;
+            let send_library = send_library.unwrap_or(DEFAULT_SEND_LIBRARY);
+            // nonce 账户的 seeds 含远端 peer 地址，先按 dst_eid 推导出 OApp 的 Peer 账户再读取
+            let peer = LayerZeroAccounts::for_config(&config, dst_eid, &[0; 32], send_library).peer;
+            // 合约只接受路由缓存的 peer；切换 OApp 程序后没有 refresh-route-peer 时提前报错
+            if route.peer != peer {
+                return Err(anyhow!("route {dst_eid} caches peer {}, expected {peer}; run refresh-route-peer first", route.peer));
+            }
+            let remote_peer = peer_address(&app.account(&peer, "LayerZero peer")?.data)
+                .ok_or_else(|| anyhow!("LayerZero peer {peer} has invalid data"))?;
+            let mut layerzero = LayerZeroAccounts::for_config(&config, dst_eid, &remote_peer, send_library);
+            let params = DepositParams { amount, dst_eid, dst_token, merchant, options, native_fee, lz_token_fee };
+            let mut instructions = Vec::new();
+            // LZ token 手续费先转入 vault_authority 的 ATA，不存在时一并创建
+            if let Some(lz_token_mint) = lz_token_mint {
+                let lz_token_program = app.token_program(&lz_token_mint)?;
+                instructions.push(create_associated_token_account_idempotent(
+                    &payer,
+                    &vault.vault_authority,
+                    &lz_token_mint,
+                    &lz_token_program,
+                ));
+                layerzero = layerzero.with_lz_token(lz_token_mint, lz_token_program);
+            }
+            instructions.push(vault.deposit_from_user(&config, payer, mint, token_program, params, &layerzero));
+            app.submit(&instructions)
+        }
+        Command::LpDeposit { mint, amount } => {
+            let token_program = app.token_program(&mint)?;
+            app.submit(&[vault.lp_deposit(payer, mint, token_program, amount)])
+        }
+        Command::LpWithdraw { mint, shares } => {
+            let token_program = app.token_program(&mint)?;
+            app.submit(&[vault.lp_withdraw(payer, mint, token_program, shares)])
+        }
+        Command::TransferOut { mint, recipient, amount } => {
+            let token_program = app.token_program(&mint)?;
+            app.submit(&[
+                create_associated_token_account_idempotent(&payer, &recipient, &mint, &token_program),
+                vault.transfer_out(payer, mint, recipient, token_program, amount),
+            ])
+        }
+        Command::ShowConfig => show_config(app),
+        Command::ShowPosition { mint, owner } => show_position(app, &mint, &owner.unwrap_or(payer)),
+    }
+}
+
+impl From<MintParams> for MintConfigParams {
+    fn from(p: MintParams) -> Self {
+        Self {
+            enabled: !p.disabled,
+            min_deposit: p.min_deposit,
+            max_deposit: p.max_deposit,
+            daily_outflow_cap: p.daily_outflow_cap,
+            dst_tokens: p.dst_tokens,
+            protocol_fee_bps: p.protocol_fee_bps,
+            lp_fee_bps: p.lp_fee_bps,
+            refund_dust: p.refund_dust,
+        }
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn show_config(app: &App) -> Result<()> {
+    let vault = &app.vault;
+    let config: Config = app.fetch(&vault.config, "config")?;
+    let callers: Vec<_> = config
+        .allowed_callers
+        .iter()
+        .map(|c| json!({ "authority": c.authority.to_string(), "roles": c.roles }))
+        .collect();
+    let peers: Vec<_> = config
+        .remote_peers
+        .iter()
+        .map(|p| json!({ "src_eid": p.src_eid, "sender": hex(&p.sender) }))
+        .collect();
+    app.print(json!({
+        "config": vault.config.to_string(),
+        "vault_id": config.vault_id,
+        "vault_authority": vault.vault_authority.to_string(),
+        "admin": config.admin.to_string(),
+        "pending_admin": config.pending_admin.to_string(),
+        "allowed_caller_authority": config.allowed_caller_authority.to_string(),
+        "allowed_callers": callers,
+        "guardian": config.guardian.to_string(),
+        "paused": config.paused,
+        "treasury": config.treasury.to_string(),
+        "remote_peers": peers,
+        "next_receipt_id": config.next_receipt_id,
+        "refund_timeout_secs": config.refund_timeout_secs,
+        "refund_fee_bps": config.refund_fee_bps,
+        "layerzero_oapp_program": config.layerzero_oapp_program.to_string(),
+        "layerzero_endpoint_program": config.layerzero_endpoint_program.to_string(),
+        "lz_store": config.lz_store.to_string(),
+        "lz_endpoint": config.lz_endpoint.to_string(),
+    }));
+    Ok(())
+}
+
+fn show_position(app: &App, mint: &Pubkey, owner: &Pubkey) -> Result<()> {
+    let vault = &app.vault;
+    let token_program = app.token_program(mint)?;
+    let lp_token = vault.lp_position(owner, mint, &token_program);
+    // 还没有 LP 份额账户视为 0 份额
+    let shares = match app.rpc.get_account(&lp_token)? {
+        Some(account) => decode_token_account(&account.data)?.amount,
+        None => 0,
+    };
+    let lp_supply = decode_mint(&app.account(&vault.lp_mint(mint), "LP mint")?.data)?.supply;
+    let vault_token_account = vault.vault_token_account(mint, &token_program);
+    let vault_balance = decode_token_account(&app.account(&vault_token_account, "vault token account")?.data)?.amount;
+    let position = LpPosition::new(shares, lp_supply, vault_balance);
+    app.print(json!({
+        "owner": owner.to_string(),
+        "mint": mint.to_string(),
+        "lp_token_account": lp_token.to_string(),
+        "shares": position.shares,
+        "lp_supply": position.lp_supply,
+        "vault_balance": position.vault_balance,
+        "underlying": position.underlying,
+    }));
+    Ok(())
+}
@@ -0,0 +1,223 @@
+//! vault-cli：基于 transfer-contract-client 的运维命令行，取代 scripts/ 下的 ts 脚本。
+//!
+//! 所有写操作都由 `--keypair` 签名并支付；`--dry-run` 只做 simulateTransaction，
+//! `--json` 输出机器可读的结果，方便 runbook 里用 jq 处理。
+
+mod app;
+mod commands;
+mod rpc;
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use anchor_lang::prelude::Pubkey;
+use clap::{Args, Parser, Subcommand};
+use transfer_contract::options::validate_options;
+use transfer_contract::state::DstToken;
+
+use crate::app::App;
+
+#[derive(Parser)]
+#[command(name = "vault-cli", version, about = "Operate a transfer_contract vault")]
+struct Cli {
+    #[command(flatten)]
+    global: GlobalArgs,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Args)]
+pub struct GlobalArgs {
+    /// 签名并支付交易的 keypair 文件（默认 ~/.config/solana/id.json）
+    #[arg(short, long, global = true, env = "VAULT_CLI_KEYPAIR")]
+    keypair: Option<PathBuf>,
+    /// RPC URL 或简写：localhost / devnet / testnet / mainnet-beta
+    #[arg(short = 'u', long = "url", global = true, default_value = "localhost", env = "VAULT_CLI_URL")]
+    url: String,
+    /// 金库编号，config = PDA(["config", vault_id])
+    #[arg(long, global = true, default_value_t = 0)]
+    vault_id: u64,
+    /// 只模拟交易，不发送
+    #[arg(long, global = true)]
+    dry_run: bool,
+    /// 以 JSON 输出结果
+    #[arg(long, global = true)]
+    json: bool,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// 初始化金库 Config（调用方成为 admin）
+    Init {
+        /// allowed_caller_authority，默认为 admin 自己
+        #[arg(long)]
+        allowed_caller: Option<Pubkey>,
+    },
+    /// 更换 allowed_caller_authority
+    SetAllowedCaller { new_allowed_caller: Pubkey },
+    /// 切换 LayerZero OApp / Endpoint 程序（如本地 mock-oapp）
+    SetLayerzeroPrograms {
+        #[arg(long)]
+        oapp_program: Pubkey,
+        #[arg(long)]
+        endpoint_program: Pubkey,
+    },
+    /// 切换 OApp 程序后按新程序重新推导路由缓存的 peer
+    RefreshRoutePeer {
+        #[arg(long)]
+        dst_eid: u32,
+    },
+    /// 把 mint 加入白名单（同时创建 LP mint 与金库 ATA）
+    AddMint {
+        mint: Pubkey,
+        #[command(flatten)]
+        params: MintParams,
+    },
+    /// 用户入金并通过 LayerZero 通知目标链
+    Deposit {
+        #[arg(long)]
+        mint: Pubkey,
+        /// 最小单位的金额
+        #[arg(long)]
+        amount: u64,
+        #[arg(long)]
+        dst_eid: u32,
+        /// 目标链代币地址（20 或 32 字节 hex）
+        #[arg(long, value_parser = parse_bytes32)]
+        dst_token: [u8; 32],
+        /// 商户地址（20 或 32 字节 hex）
+        #[arg(long, value_parser = parse_bytes32)]
+        merchant: [u8; 32],
+        /// LayerZero type-3 options（hex），需设置 lzReceive gas；默认按路由的 executor 配置生成
+        #[arg(long, value_parser = parse_options)]
+        options: Option<Vec<u8>>,
+        /// endpoint::send 使用的发送库，默认 SendUln302
+        #[arg(long)]
+        send_library: Option<Pubkey>,
+        /// 原生代币手续费上限（lamports），实际按报价收取
+        #[arg(long)]
+        native_fee: Option<u64>,
+        /// 用 LZ token 支付手续费时的手续费上限，需同时传入 --lz-token-mint
+        #[arg(long, requires = "lz_token_mint")]
+        lz_token_fee: Option<u64>,
+        /// LZ token 的 mint
+        #[arg(long, requires = "lz_token_fee")]
+        lz_token_mint: Option<Pubkey>,
+    },
+    /// 存入底层资产，换取 LP 份额
+    LpDeposit {
+        #[arg(long)]
+        mint: Pubkey,
+        #[arg(long)]
+        amount: u64,
+    },
+    /// 赎回 LP 份额
+    LpWithdraw {
+        #[arg(long)]
+        mint: Pubkey,
+        #[arg(long)]
+        shares: u64,
+    },
+    /// 从金库打款到 recipient 的 ATA（不存在时先创建）
+    TransferOut {
+        #[arg(long)]
+        mint: Pubkey,
+        #[arg(long)]
+        recipient: Pubkey,
+        #[arg(long)]
+        amount: u64,
+    },
+    /// 显示金库 Config
+    ShowConfig,
+    /// 显示 owner（默认为 keypair）在某个 mint 下的 LP 头寸
+    ShowPosition {
+        #[arg(long)]
+        mint: Pubkey,
+        #[arg(long)]
+        owner: Option<Pubkey>,
+    },
+}
+
+/// add_allowed_mint 的 MintConfigParams
+#[derive(Args)]
+pub struct MintParams {
+    /// 以禁用状态加入
+    #[arg(long)]
+    disabled: bool,
+    #[arg(long, default_value_t = 0)]
+    min_deposit: u64,
+    /// 0 表示不限
+    #[arg(long, default_value_t = 0)]
+    max_deposit: u64,
+    /// 0 表示不限
+    #[arg(long, default_value_t = 0)]
+    daily_outflow_cap: u64,
+    /// 目标链代币：<dst_eid>:<hex 地址>:<decimals>，可重复
+    #[arg(long = "dst-token", value_parser = parse_dst_token)]
+    dst_tokens: Vec<DstToken>,
+    #[arg(long, default_value_t = 0)]
+    protocol_fee_bps: u16,
+    #[arg(long, default_value_t = 0)]
+    lp_fee_bps: u16,
+    /// decimals 换算的尾差退还给用户（默认留在金库）
+    #[arg(long)]
+    refund_dust: bool,
+}
+
+fn parse_hex(s: &str) -> Result<Vec<u8>, String> {
+    let s = s.strip_prefix("0x").unwrap_or(s);
+    // 按字节切片前先排除多字节字符
+    if !s.is_ascii() {
+        return Err("expected hex digits".into());
+    }
+    if s.len() % 2 != 0 {
+        return Err("odd number of hex digits".into());
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).map_err(|e| e.to_string()))
+        .collect()
+}
+
+/// 与合约相同的校验：type-3 且设置了 lzReceive gas
+fn parse_options(s: &str) -> Result<Vec<u8>, String> {
+    let options = parse_hex(s)?;
+    validate_options(&options).map_err(|e| format!("invalid LayerZero options: {e}"))?;
+    Ok(options)
+}
+
+/// EVM 地址左边补 12 个 0
+fn parse_bytes32(s: &str) -> Result<[u8; 32], String> {
+    let bytes = parse_hex(s)?;
+    match bytes.len() {
+        20 | 32 => {
+            let mut out = [0u8; 32];
+            out[32 - bytes.len()..].copy_from_slice(&bytes);
+            Ok(out)
+        }
+        n => Err(format!("expected 20 or 32 bytes, got {n}")),
+    }
+}
+
+fn parse_dst_token(s: &str) -> Result<DstToken, String> {
+    let [dst_eid, token, decimals] = s.split(':').collect::<Vec<_>>()[..] else {
+        return Err("expected <dst_eid>:<token>:<decimals>".into());
+    };
+    Ok(DstToken {
+        dst_eid: dst_eid.parse().map_err(|e| format!("dst_eid: {e}"))?,
+        token: parse_bytes32(token)?,
+        decimals: decimals.parse().map_err(|e| format!("decimals: {e}"))?,
+    })
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    // 错误一律写到 stderr，stdout 只有命令结果，--json 时始终是单个 JSON 文档
+    match App::new(&cli.global).and_then(|app| commands::run(&app, cli.command)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! 最小的 Solana JSON-RPC 客户端：只实现 CLI 用到的几个方法。
+
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use anchor_lang::prelude::Pubkey;
+use anyhow::{anyhow, bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::{json, Value};
+use solana_hash::Hash;
+use solana_transaction::Transaction;
+
+const CONFIRM_TIMEOUT: Duration = Duration::from_secs(60);
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// `-u` 接受的简写，其余按 URL 处理
+pub fn cluster_url(moniker: &str) -> String {
+    match moniker {
+        "l" | "localhost" | "localnet" => "http://127.0.0.1:8899",
+        "d" | "devnet" => "https://api.devnet.solana.com",
+        "t" | "testnet" => "https://api.testnet.solana.com",
+        "m" | "mainnet" | "mainnet-beta" => "https://api.mainnet-beta.solana.com",
+        url => url,
+    }
+    .to_string()
+}
+
+pub struct Account {
+    pub owner: Pubkey,
+    pub data: Vec<u8>,
+}
+
+/// simulateTransaction 的结果
+pub struct Simulation {
+    pub err: Value,
+    pub logs: Vec<String>,
+    pub units_consumed: Option<u64>,
+}
+
+pub struct RpcClient {
+    url: String,
+    agent: ureq::Agent,
+}
+
+impl RpcClient {
+    pub fn new(url: String) -> Self {
+        Self { url, agent: ureq::Agent::new() }
+    }
+
+    fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let request = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let response: Value = self
+            .agent
+            .post(&self.url)
+            .send_json(request)
+            .with_context(|| format!("{method} request to {} failed", self.url))?
+            .into_json()?;
+        if let Some(error) = response.get("error") {
+            bail!("{method}: {error}");
+        }
+        response.get("result").cloned().ok_or_else(|| anyhow!("{method}: missing result"))
+    }
+
+    /// 账户不存在时返回 None
+    pub fn get_account(&self, address: &Pubkey) -> Result<Option<Account>> {
+        let result = self.call(
+            "getAccountInfo",
+            json!([address.to_string(), { "encoding": "base64", "commitment": "confirmed" }]),
+        )?;
+        let value = &result["value"];
+        if value.is_null() {
+            return Ok(None);
+        }
+        let owner = value["owner"].as_str().unwrap_or_default();
+        let data = value["data"][0].as_str().unwrap_or_default();
+        Ok(Some(Account {
+            owner: owner.parse().map_err(|_| anyhow!("invalid owner {owner}"))?,
+            data: STANDARD.decode(data)?,
+        }))
+    }
+
+    pub fn latest_blockhash(&self) -> Result<Hash> {
+        let result = self.call("getLatestBlockhash", json!([{ "commitment": "confirmed" }]))?;
+        let blockhash = result["value"]["blockhash"].as_str().unwrap_or_default();
+        blockhash.parse().map_err(|_| anyhow!("invalid blockhash {blockhash}"))
+    }
+
+    pub fn simulate(&self, transaction: &Transaction) -> Result<Simulation> {
+        let result = self.call(
+            "simulateTransaction",
+            json!([
+                encode(transaction)?,
+                { "encoding": "base64", "commitment": "confirmed", "sigVerify": false, "replaceRecentBlockhash": true }
+            ]),
+        )?;
+        let value = &result["value"];
+        Ok(Simulation {
+            err: value["err"].clone(),
+            logs: serde_json::from_value(value["logs"].clone()).unwrap_or_default(),
+            units_consumed: value["unitsConsumed"].as_u64(),
+        })
+    }
+
+    /// 发送并等待 confirmed；交易执行失败时返回错误
+    pub fn send_and_confirm(&self, transaction: &Transaction) -> Result<String> {
+        let signature = self.call(
+            "sendTransaction",
+            json!([encode(transaction)?, { "encoding": "base64", "preflightCommitment": "confirmed" }]),
+        )?;
+        let signature = signature.as_str().ok_or_else(|| anyhow!("sendTransaction: invalid signature"))?.to_string();
+
+        let start = Instant::now();
+        while start.elapsed() < CONFIRM_TIMEOUT {
+            let result = self.call("getSignatureStatuses", json!([[signature]]))?;
+            let status = &result["value"][0];
+            if !status.is_null() {
+                if !status["err"].is_null() {
+                    bail!("transaction {signature} failed: {}", status["err"]);
+                }
+                if matches!(status["confirmationStatus"].as_str(), Some("confirmed" | "finalized")) {
+                    return Ok(signature);
+                }
+            }
+            sleep(POLL_INTERVAL);
+        }
+        bail!("transaction {signature} was not confirmed within {}s", CONFIRM_TIMEOUT.as_secs())
+    }
+}
+
+fn encode(transaction: &Transaction) -> Result<String> {
+    Ok(STANDARD.encode(bincode::serialize(transaction)?))
+}
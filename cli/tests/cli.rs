@@ -0,0 +1,60 @@
+use std::process::{Command, Output};
+
+fn vault_cli(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_vault-cli")).args(args).output().unwrap()
+}
+
+#[test]
+fn help_lists_all_commands() {
+    let output = vault_cli(&["--help"]);
+    assert!(output.status.success());
+    let help = String::from_utf8(output.stdout).unwrap();
+    for command in [
+        "init",
+        "set-allowed-caller",
+        "set-layerzero-programs",
+        "add-mint",
+        "deposit",
+        "lp-deposit",
+        "lp-withdraw",
+        "transfer-out",
+        "show-config",
+        "show-position",
+    ] {
+        assert!(help.contains(command), "missing {command}");
+    }
+}
+
+#[test]
+fn arguments_are_validated_before_anything_is_sent() {
+    let mint = "So11111111111111111111111111111111111111112";
+    let base = ["deposit", "--mint", mint, "--amount", "1", "--dst-eid", "30101", "--merchant", "0x01"];
+    // 19 字节既不是 EVM 地址也不是 32 字节地址
+    let output = vault_cli(&[&base[..], &["--dst-token", &format!("0x{}", "ab".repeat(19))]].concat());
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("expected 20 or 32 bytes"));
+
+    // options 缺少 lzReceive gas
+    let address = format!("0x{}", "ab".repeat(20));
+    let args = ["--merchant", &address, "--dst-token", &address, "--options", "0x0003"];
+    let output = vault_cli(&[&base[..base.len() - 2], &args].concat());
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid LayerZero options"));
+
+    // 非 ASCII 输入报参数错误而不是 panic
+    let output = vault_cli(&[&base[..base.len() - 1], &["aé0"]].concat());
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("expected hex digits"));
+
+    let output = vault_cli(&["add-mint", mint, "--dst-token", "30101:0x01"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("<dst_eid>:<token>:<decimals>"));
+}
+
+#[test]
+fn missing_keypair_is_reported_on_stderr() {
+    let output = vault_cli(&["--json", "--keypair", "/nonexistent/id.json", "show-config"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("failed to read keypair /nonexistent/id.json"));
+}
@@ -0,0 +1,51 @@
+//! 链上账户的反序列化。程序账户校验 anchor discriminator；
+//! LP 头寸就是用户持有的 LP mint 代币，按金库余额换算成底层资产。
+
+use anchor_lang::{AccountDeserialize, Result};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use transfer_contract::state::shares::assets_for_shares;
+
+pub use transfer_contract::state::{Config, MintConfig, PaymentReceipt, ReceivedMessage, Route};
+
+/// 反序列化本程序的账户（Config / MintConfig / Route / PaymentReceipt / ReceivedMessage）
+pub fn decode<T: AccountDeserialize>(mut data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut data)
+}
+
+/// SPL Token / Token-2022 代币账户（含扩展）
+pub fn decode_token_account(mut data: &[u8]) -> Result<TokenAccount> {
+    TokenAccount::try_deserialize(&mut data)
+}
+
+pub fn decode_mint(mut data: &[u8]) -> Result<Mint> {
+    Mint::try_deserialize(&mut data)
+}
+
+/// 用户在某个 mint 下的 LP 头寸。
+///
+/// 取代早期按 [LP_SEED, ...] 存储 1:1 存款金额的 `LiquidityPosition` 账户：LP 改为持有 LP mint 代币后，
+/// 链上不再有头寸账户可解码，头寸由用户的 LP 代币余额、LP 总供应量与金库余额换算得出
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LpPosition {
+    pub shares: u64,
+    pub lp_supply: u64,
+    /// 金库中该 mint 的余额，即全部 LP 的总资产
+    pub vault_balance: u64,
+    /// 现在赎回全部份额可取回的底层资产（不含 Token-2022 转账手续费）
+    pub underlying: u64,
+}
+
+impl LpPosition {
+    pub fn new(shares: u64, lp_supply: u64, vault_balance: u64) -> Self {
+        let underlying = assets_for_shares(shares, vault_balance, lp_supply).unwrap_or(0);
+        Self { shares, lp_supply, vault_balance, underlying }
+    }
+
+    /// 由用户的 LP 代币账户、LP mint、金库代币账户的原始数据构造
+    pub fn from_accounts(user_lp_token: &[u8], lp_mint: &[u8], vault_token_account: &[u8]) -> Result<Self> {
+        let shares = decode_token_account(user_lp_token)?.amount;
+        let lp_supply = decode_mint(lp_mint)?.supply;
+        let vault_balance = decode_token_account(vault_token_account)?.amount;
+        Ok(Self::new(shares, lp_supply, vault_balance))
+    }
+}
@@ -0,0 +1,77 @@
+//! 程序事件的解码。`emit!` 把 discriminator + borsh 数据以 base64 写入 "Program data: " 日志。
+
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use transfer_contract::instructions::{
+    accept_admin::AdminAccepted, add_allowed_mint::AllowedMintAdded, cancel_admin_proposal::AdminProposalCancelled,
+    claim_refund::RefundClaimed, deposit_from_user::DepositEvent, lp_deposit::LpDepositEvent,
+    lp_withdraw::LpWithdrawEvent, lz_receive::LzReceiveEvent, mark_payment_failed::PaymentStatusUpdated,
+    pause::PauseUpdated, propose_admin::AdminProposed, refresh_route_peer::RoutePeerUpdated, set_layerzero_programs::LayerZeroProgramsUpdated, remove_allowed_mint::AllowedMintRemoved,
+    set_caller_roles::CallerRolesUpdated, set_guardian::GuardianUpdated, set_refund_policy::RefundPolicyUpdated,
+    set_remote_peer::RemotePeerUpdated, set_route::RouteUpdated, set_treasury::TreasuryUpdated,
+    transfer_out::TransferOutEvent, update_mint_config::MintConfigUpdated,
+};
+
+const PROGRAM_DATA: &str = "Program data: ";
+
+macro_rules! vault_events {
+    ($($variant:ident($event:ty)),* $(,)?) => {
+        /// 本程序的全部事件
+        pub enum VaultEvent {
+            $($variant($event),)*
+        }
+
+        /// 按 discriminator 解码一条事件；不是本程序的事件时返回 None
+        pub fn decode_event(data: &[u8]) -> Option<VaultEvent> {
+            $(
+                if let Some(mut body) = data.strip_prefix(<$event as Discriminator>::DISCRIMINATOR) {
+                    return <$event>::deserialize(&mut body).ok().map(VaultEvent::$variant);
+                }
+            )*
+            None
+        }
+
+        impl VaultEvent {
+            /// 事件名（即 enum 变体名），便于日志与 JSON 输出
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(VaultEvent::$variant(_) => stringify!($variant),)*
+                }
+            }
+        }
+    };
+}
+
+vault_events! {
+    Deposit(DepositEvent),
+    TransferOut(TransferOutEvent),
+    LpDeposit(LpDepositEvent),
+    LpWithdraw(LpWithdrawEvent),
+    LzReceive(LzReceiveEvent),
+    RefundClaimed(RefundClaimed),
+    PaymentStatusUpdated(PaymentStatusUpdated),
+    AllowedMintAdded(AllowedMintAdded),
+    AllowedMintRemoved(AllowedMintRemoved),
+    MintConfigUpdated(MintConfigUpdated),
+    RouteUpdated(RouteUpdated),
+    RoutePeerUpdated(RoutePeerUpdated),
+    RefundPolicyUpdated(RefundPolicyUpdated),
+    RemotePeerUpdated(RemotePeerUpdated),
+    TreasuryUpdated(TreasuryUpdated),
+    LayerZeroProgramsUpdated(LayerZeroProgramsUpdated),
+    CallerRolesUpdated(CallerRolesUpdated),
+    GuardianUpdated(GuardianUpdated),
+    PauseUpdated(PauseUpdated),
+    AdminProposed(AdminProposed),
+    AdminAccepted(AdminAccepted),
+    AdminProposalCancelled(AdminProposalCancelled),
+}
+
+/// 从交易日志中取出本程序的事件（其它程序的 "Program data" 因 discriminator 不匹配被跳过）
+pub fn parse_logs<S: AsRef<str>>(logs: &[S]) -> Vec<VaultEvent> {
+    logs.iter()
+        .filter_map(|line| line.as_ref().strip_prefix(PROGRAM_DATA))
+        .filter_map(|data| STANDARD.decode(data).ok())
+        .filter_map(|data| decode_event(&data))
+        .collect()
+}
@@ -0,0 +1,337 @@
+//! 指令构造。代币账户默认使用 ATA，token_program 需与 mint 所属程序一致（SPL Token 或 Token-2022）。
+//! lz_receive / lz_receive_ack 只能由 OApp 通过 CPI 调用，这里不提供。
+
+use anchor_lang::prelude::{AccountMeta, Pubkey};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token;
+use transfer_contract::instructions::deposit_from_user::DepositParams;
+use transfer_contract::state::{Config, MintConfigParams, RouteParams};
+use transfer_contract::{accounts, instruction};
+
+use crate::layerzero::LayerZeroAccounts;
+use crate::{pda, Vault, PROGRAM_ID};
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction { program_id: PROGRAM_ID, accounts: accounts.to_account_metas(None), data: data.data() }
+}
+
+impl Vault {
+    pub fn initialize(&self, admin: Pubkey, allowed_caller_authority: Pubkey) -> Instruction {
+        build(
+            accounts::Initialize {
+                config: self.config,
+                vault_authority: self.vault_authority,
+                admin,
+                system_program: system_program::ID,
+            },
+            instruction::Initialize { vault_id: self.vault_id, allowed_caller_authority },
+        )
+    }
+
+    /// `config` 为链上最新的 Config：回执编号取自 next_receipt_id，协议手续费转入 treasury 的 ATA。
+    /// 未设置 `layerzero.lz_token` 时，LZ token 相关的可选账户以程序 ID 占位
+    pub fn deposit_from_user(
+        &self,
+        config: &Config,
+        user: Pubkey,
+        mint: Pubkey,
+        token_program: Pubkey,
+        params: DepositParams,
+        layerzero: &LayerZeroAccounts,
+    ) -> Instruction {
+        let mut ix = build(
+            accounts::DepositFromUser {
+                config: self.config,
+                user,
+                receipt: self.receipt(config.next_receipt_id),
+                user_source_token: pda::token_account(&user, &mint, &token_program),
+                vault_authority: self.vault_authority,
+                vault_token_account: self.vault_token_account(&mint, &token_program),
+                treasury_token_account: pda::token_account(&config.treasury, &mint, &token_program),
+                mint,
+                mint_config: self.mint_config(&mint),
+                route: self.route(params.dst_eid),
+                token_program,
+                layerzero_oapp_program: layerzero.oapp_program,
+                peer: layerzero.peer,
+                store: layerzero.store,
+                endpoint: layerzero.endpoint,
+                endpoint_program: layerzero.endpoint_program,
+                system_program: system_program::ID,
+                lz_token_mint: layerzero.lz_token.map(|t| t.mint),
+                user_lz_token_account: layerzero.lz_token.map(|t| pda::token_account(&user, &t.mint, &t.token_program)),
+                vault_lz_token_account: layerzero
+                    .lz_token
+                    .map(|t| pda::token_account(&self.vault_authority, &t.mint, &t.token_program)),
+                lz_token_program: layerzero.lz_token.map(|t| t.token_program),
+            },
+            instruction::DepositFromUser { params },
+        );
+        ix.accounts.extend(layerzero.remaining_accounts());
+        ix
+    }
+
+    /// 只读报价，配合 simulateTransaction 读取 return data 中的 MessagingFee；与 deposit 一样追加发送库账户
+    pub fn quote_deposit(&self, mint: Pubkey, params: DepositParams, layerzero: &LayerZeroAccounts) -> Instruction {
+        let mut ix = build(
+            accounts::QuoteDeposit {
+                config: self.config,
+                mint,
+                mint_config: self.mint_config(&mint),
+                route: self.route(params.dst_eid),
+                layerzero_oapp_program: layerzero.oapp_program,
+                peer: layerzero.peer,
+                store: layerzero.store,
+                endpoint: layerzero.endpoint,
+                endpoint_program: layerzero.endpoint_program,
+            },
+            instruction::QuoteDeposit { params },
+        );
+        ix.accounts.extend(layerzero.remaining_accounts());
+        ix
+    }
+
+    /// 打款给 recipient 的 ATA；authority 为 admin、allowed_caller_authority 或拥有 ROLE_PAYOUT 的调用方
+    pub fn transfer_out(
+        &self,
+        authority: Pubkey,
+        mint: Pubkey,
+        recipient: Pubkey,
+        token_program: Pubkey,
+        amount: u64,
+    ) -> Instruction {
+        build(
+            accounts::TransferOut {
+                config: self.config,
+                authority,
+                vault_authority: self.vault_authority,
+                vault_token_account: self.vault_token_account(&mint, &token_program),
+                recipient_token_account: pda::token_account(&recipient, &mint, &token_program),
+                mint,
+                mint_config: self.mint_config(&mint),
+                token_program,
+            },
+            instruction::TransferOut { amount },
+        )
+    }
+
+    pub fn lp_deposit(&self, user: Pubkey, mint: Pubkey, token_program: Pubkey, amount: u64) -> Instruction {
+        build(
+            accounts::LpDeposit {
+                config: self.config,
+                user,
+                user_source_token: pda::token_account(&user, &mint, &token_program),
+                vault_authority: self.vault_authority,
+                vault_token_account: self.vault_token_account(&mint, &token_program),
+                mint,
+                mint_config: self.mint_config(&mint),
+                lp_mint: self.lp_mint(&mint),
+                user_lp_token: self.lp_position(&user, &mint, &token_program),
+                token_program,
+                associated_token_program: associated_token::ID,
+                system_program: system_program::ID,
+            },
+            instruction::LpDeposit { amount },
+        )
+    }
+
+    pub fn lp_withdraw(&self, user: Pubkey, mint: Pubkey, token_program: Pubkey, shares: u64) -> Instruction {
+        build(
+            accounts::LpWithdraw {
+                config: self.config,
+                user,
+                vault_authority: self.vault_authority,
+                vault_token_account: self.vault_token_account(&mint, &token_program),
+                user_destination_token: pda::token_account(&user, &mint, &token_program),
+                mint,
+                mint_config: self.mint_config(&mint),
+                lp_mint: self.lp_mint(&mint),
+                user_lp_token: self.lp_position(&user, &mint, &token_program),
+                token_program,
+            },
+            instruction::LpWithdraw { shares },
+        )
+    }
+
+    pub fn claim_refund(
+        &self,
+        user: Pubkey,
+        receipt_id: u64,
+        mint: Pubkey,
+        token_program: Pubkey,
+    ) -> Instruction {
+        build(
+            accounts::ClaimRefund {
+                config: self.config,
+                user,
+                receipt: self.receipt(receipt_id),
+                vault_authority: self.vault_authority,
+                vault_token_account: self.vault_token_account(&mint, &token_program),
+                user_token_account: pda::token_account(&user, &mint, &token_program),
+                mint,
+                mint_config: self.mint_config(&mint),
+                token_program,
+            },
+            instruction::ClaimRefund {},
+        )
+    }
+
+    // ===== admin =====
+
+    pub fn set_allowed_caller(&self, admin: Pubkey, new_allowed_caller: Pubkey) -> Instruction {
+        build(
+            accounts::SetAllowedCaller { config: self.config, admin },
+            instruction::SetAllowedCaller { new_allowed_caller },
+        )
+    }
+
+    pub fn set_caller_roles(&self, admin: Pubkey, caller: Pubkey, roles: u8) -> Instruction {
+        build(
+            accounts::SetCallerRoles { config: self.config, admin },
+            instruction::SetCallerRoles { caller, roles },
+        )
+    }
+
+    pub fn add_allowed_mint(
+        &self,
+        admin: Pubkey,
+        mint: Pubkey,
+        token_program: Pubkey,
+        params: MintConfigParams,
+    ) -> Instruction {
+        build(
+            accounts::AddAllowedMint {
+                config: self.config,
+                mint_config: self.mint_config(&mint),
+                admin,
+                vault_authority: self.vault_authority,
+                mint,
+                lp_mint: self.lp_mint(&mint),
+                token_program,
+                system_program: system_program::ID,
+            },
+            instruction::AddAllowedMint { params },
+        )
+    }
+
+    pub fn remove_allowed_mint(&self, admin: Pubkey, mint: Pubkey, token_program: Pubkey) -> Instruction {
+        build(
+            accounts::RemoveAllowedMint {
+                config: self.config,
+                mint_config: self.mint_config(&mint),
+                admin,
+                vault_authority: self.vault_authority,
+                vault_token_account: self.vault_token_account(&mint, &token_program),
+                mint,
+                lp_mint: self.lp_mint(&mint),
+                token_program,
+            },
+            instruction::RemoveAllowedMint {},
+        )
+    }
+
+    pub fn update_mint_config(&self, admin: Pubkey, mint: Pubkey, params: MintConfigParams) -> Instruction {
+        build(
+            accounts::UpdateMintConfig { config: self.config, mint_config: self.mint_config(&mint), admin, mint },
+            instruction::UpdateMintConfig { params },
+        )
+    }
+
+    pub fn propose_admin(&self, admin: Pubkey, new_admin: Pubkey) -> Instruction {
+        build(accounts::ProposeAdmin { config: self.config, admin }, instruction::ProposeAdmin { new_admin })
+    }
+
+    pub fn accept_admin(&self, new_admin: Pubkey) -> Instruction {
+        build(accounts::AcceptAdmin { config: self.config, new_admin }, instruction::AcceptAdmin {})
+    }
+
+    pub fn cancel_admin_proposal(&self, admin: Pubkey) -> Instruction {
+        build(accounts::CancelAdminProposal { config: self.config, admin }, instruction::CancelAdminProposal {})
+    }
+
+    pub fn set_guardian(&self, admin: Pubkey, guardian: Pubkey) -> Instruction {
+        build(accounts::SetGuardian { config: self.config, admin }, instruction::SetGuardian { guardian })
+    }
+
+    /// authority 为 admin 或 guardian
+    pub fn pause(&self, authority: Pubkey, flags: u8) -> Instruction {
+        build(accounts::Pause { config: self.config, authority }, instruction::Pause { flags })
+    }
+
+    pub fn unpause(&self, admin: Pubkey, flags: u8) -> Instruction {
+        build(accounts::Unpause { config: self.config, admin }, instruction::Unpause { flags })
+    }
+
+    pub fn pause_mint(&self, authority: Pubkey, mint: Pubkey, flags: u8) -> Instruction {
+        build(
+            accounts::PauseMint { config: self.config, mint_config: self.mint_config(&mint), authority, mint },
+            instruction::PauseMint { flags },
+        )
+    }
+
+    pub fn unpause_mint(&self, admin: Pubkey, mint: Pubkey, flags: u8) -> Instruction {
+        build(
+            accounts::UnpauseMint { config: self.config, mint_config: self.mint_config(&mint), admin, mint },
+            instruction::UnpauseMint { flags },
+        )
+    }
+
+    pub fn set_treasury(&self, admin: Pubkey, treasury: Pubkey) -> Instruction {
+        build(accounts::SetTreasury { config: self.config, admin }, instruction::SetTreasury { treasury })
+    }
+
+    pub fn set_remote_peer(&self, admin: Pubkey, src_eid: u32, sender: [u8; 32]) -> Instruction {
+        build(
+            accounts::SetRemotePeer { config: self.config, admin },
+            instruction::SetRemotePeer { src_eid, sender },
+        )
+    }
+
+    pub fn set_route(&self, admin: Pubkey, dst_eid: u32, params: RouteParams) -> Instruction {
+        build(
+            accounts::SetRoute {
+                config: self.config,
+                route: self.route(dst_eid),
+                admin,
+                system_program: system_program::ID,
+            },
+            instruction::SetRoute { dst_eid, params },
+        )
+    }
+
+    pub fn set_layerzero_programs(&self, admin: Pubkey, oapp_program: Pubkey, endpoint_program: Pubkey) -> Instruction {
+        build(
+            accounts::SetLayerzeroPrograms { config: self.config, admin },
+            instruction::SetLayerzeroPrograms { oapp_program, endpoint_program },
+        )
+    }
+
+    /// set_layerzero_programs 切换 OApp 程序后按新程序重新推导路由缓存的 peer
+    pub fn refresh_route_peer(&self, admin: Pubkey, dst_eid: u32) -> Instruction {
+        build(
+            accounts::RefreshRoutePeer { config: self.config, route: self.route(dst_eid), admin },
+            instruction::RefreshRoutePeer {},
+        )
+    }
+
+    pub fn set_refund_policy(&self, admin: Pubkey, timeout_secs: i64, fee_bps: u16) -> Instruction {
+        build(
+            accounts::SetRefundPolicy { config: self.config, admin },
+            instruction::SetRefundPolicy { timeout_secs, fee_bps },
+        )
+    }
+
+    pub fn mark_payment_failed(&self, admin: Pubkey, receipt_id: u64) -> Instruction {
+        build(
+            accounts::MarkPaymentFailed { config: self.config, admin, receipt: self.receipt(receipt_id) },
+            instruction::MarkPaymentFailed {},
+        )
+    }
+}
+
+/// 方便调用方自行追加账户（例如 Token-2022 transfer hook 需要的额外账户）
+pub fn with_remaining_accounts(mut ix: Instruction, remaining: impl IntoIterator<Item = AccountMeta>) -> Instruction {
+    ix.accounts.extend(remaining);
+    ix
+}
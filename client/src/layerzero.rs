@@ -0,0 +1,123 @@
+//! deposit_from_user / quote_deposit 需要的 LayerZero 账户。
+//!
+//! 具名账户（OApp 程序、peer、store、endpoint、endpoint 程序）对应指令的账户结构；
+//! endpoint::send 额外需要的发送库账户以 remaining accounts 的形式追加在指令末尾。
+
+use anchor_lang::prelude::{AccountMeta, Pubkey};
+use anchor_lang::pubkey;
+use transfer_contract::instructions::deposit_from_user::{
+    endpoint_pda, peer_pda, store_pda, LAYERZERO_ENDPOINT_PROGRAM_ID, LAYERZERO_OAPP_PROGRAM_ID,
+};
+/// Peer 账户中的远端地址，与合约填入 quoteSend receiver 的取法相同
+pub use transfer_contract::instructions::deposit_from_user::peer_address;
+use transfer_contract::state::Config;
+
+/// devnet 默认的发送库（SendUln302）
+pub const DEFAULT_SEND_LIBRARY: Pubkey = pubkey!("2XgGZG4oP29U3w5h4nTk1V2LFHL23zKDPJjs3psGzLKQ");
+
+pub const SEND_LIBRARY_CONFIG_SEED: &[u8] = b"SendLibraryConfig";
+pub const MESSAGE_LIB_SEED: &[u8] = b"MessageLib";
+pub const NONCE_SEED: &[u8] = b"Nonce";
+
+/// initialize 写入 Config 的默认 OApp 程序；实际使用的以 `config.layerzero_oapp_program` 为准
+pub fn oapp_program() -> Pubkey {
+    LAYERZERO_OAPP_PROGRAM_ID
+}
+
+pub fn endpoint_program() -> Pubkey {
+    LAYERZERO_ENDPOINT_PROGRAM_ID
+}
+
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LayerZeroAccounts {
+    pub oapp_program: Pubkey,
+    pub peer: Pubkey,
+    pub store: Pubkey,
+    pub endpoint: Pubkey,
+    pub endpoint_program: Pubkey,
+    pub send_library: Pubkey,
+    pub send_library_config: Pubkey,
+    pub default_send_library_config: Pubkey,
+    pub send_library_info: Pubkey,
+    /// 发送序号账户，endpoint::send 会递增，需可写
+    pub nonce: Pubkey,
+    /// 用 LZ token 支付手续费时的 LZ token（见 [`LayerZeroAccounts::with_lz_token`]）
+    pub lz_token: Option<LzToken>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LzToken {
+    pub mint: Pubkey,
+    pub token_program: Pubkey,
+}
+
+impl LayerZeroAccounts {
+    /// 使用默认的 OApp / Endpoint 程序推导。`remote_peer` 为 Peer 账户中记录的远端地址（见 [`peer_address`]）
+    pub fn new(dst_eid: u32, remote_peer: &[u8; 32], send_library: Pubkey) -> Self {
+        Self::with_programs(oapp_program(), endpoint_program(), dst_eid, remote_peer, send_library)
+    }
+
+    /// 使用金库 Config 中配置的 OApp / Endpoint 程序推导
+    pub fn for_config(config: &Config, dst_eid: u32, remote_peer: &[u8; 32], send_library: Pubkey) -> Self {
+        Self::with_programs(
+            config.layerzero_oapp_program,
+            config.layerzero_endpoint_program,
+            dst_eid,
+            remote_peer,
+            send_library,
+        )
+    }
+
+    pub fn with_programs(
+        oapp_program: Pubkey,
+        endpoint_program: Pubkey,
+        dst_eid: u32,
+        remote_peer: &[u8; 32],
+        send_library: Pubkey,
+    ) -> Self {
+        let store = store_pda(&oapp_program);
+        let peer = peer_pda(&oapp_program, dst_eid);
+        let endpoint = endpoint_pda(&endpoint_program);
+        let dst_eid = dst_eid.to_be_bytes();
+        let (send_library_config, _) =
+            Pubkey::find_program_address(&[SEND_LIBRARY_CONFIG_SEED, store.as_ref(), &dst_eid], &endpoint_program);
+        let (default_send_library_config, _) =
+            Pubkey::find_program_address(&[SEND_LIBRARY_CONFIG_SEED, &dst_eid], &endpoint_program);
+        let (send_library_info, _) =
+            Pubkey::find_program_address(&[MESSAGE_LIB_SEED, send_library.as_ref()], &endpoint_program);
+        let (nonce, _) =
+            Pubkey::find_program_address(&[NONCE_SEED, store.as_ref(), &dst_eid, remote_peer], &endpoint_program);
+        Self {
+            oapp_program,
+            peer,
+            store,
+            endpoint,
+            endpoint_program,
+            send_library,
+            send_library_config,
+            default_send_library_config,
+            send_library_info,
+            nonce,
+            lz_token: None,
+        }
+    }
+
+    /// 用 LZ token 支付手续费（DepositParams::lz_token_fee 需同时传入）；
+    /// 手续费从用户的 ATA 转入 vault_authority 的 ATA，后者需事先存在
+    pub fn with_lz_token(mut self, mint: Pubkey, token_program: Pubkey) -> Self {
+        self.lz_token = Some(LzToken { mint, token_program });
+        self
+    }
+
+    /// 追加在 deposit_from_user 账户列表之后的 endpoint::send 账户
+    pub fn remaining_accounts(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.send_library, false),
+            AccountMeta::new_readonly(self.send_library_config, false),
+            AccountMeta::new_readonly(self.default_send_library_config, false),
+            AccountMeta::new_readonly(self.send_library_info, false),
+            AccountMeta::new(self.nonce, false),
+        ]
+    }
+}
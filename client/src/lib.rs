@@ -0,0 +1,61 @@
+//! transfer_contract 的 Rust 客户端：
+//! - [`pda`]：推导 config、vault_authority、mint_config、LP mint / LP 份额账户等地址；
+//! - [`Vault`]：绑定一个金库（vault_id），构造各条指令；
+//! - [`layerzero`]：deposit_from_user / quote_deposit 需要的 LayerZero 账户；
+//! - [`accounts`] / [`events`]：反序列化链上账户与程序日志中的事件。
+//!
+//! 指令的账户顺序与参数直接取自程序 crate（anchor 生成的 `accounts::*` / `instruction::*`），
+//! 程序改动账户列表时这里会编译失败而不是静默出错。
+
+pub mod accounts;
+pub mod events;
+pub mod instructions;
+pub mod layerzero;
+pub mod pda;
+
+pub use transfer_contract;
+pub use transfer_contract::ID as PROGRAM_ID;
+
+use anchor_lang::prelude::Pubkey;
+
+/// 一个金库实例：config = PDA(["config", vault_id])，vault_authority = PDA(["vault", config])
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Vault {
+    pub vault_id: u64,
+    pub config: Pubkey,
+    pub vault_authority: Pubkey,
+}
+
+impl Vault {
+    pub fn new(vault_id: u64) -> Self {
+        let (config, _) = pda::config(vault_id);
+        let (vault_authority, _) = pda::vault_authority(&config);
+        Self { vault_id, config, vault_authority }
+    }
+
+    pub fn mint_config(&self, mint: &Pubkey) -> Pubkey {
+        pda::mint_config(&self.config, mint).0
+    }
+
+    pub fn lp_mint(&self, mint: &Pubkey) -> Pubkey {
+        pda::lp_mint(&self.config, mint).0
+    }
+
+    pub fn route(&self, dst_eid: u32) -> Pubkey {
+        pda::route(&self.config, dst_eid).0
+    }
+
+    pub fn receipt(&self, id: u64) -> Pubkey {
+        pda::receipt(&self.config, id).0
+    }
+
+    /// 金库在 mint 下的 ATA
+    pub fn vault_token_account(&self, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+        pda::token_account(&self.vault_authority, mint, token_program)
+    }
+
+    /// user 在该金库 mint 下的 LP 份额 ATA
+    pub fn lp_position(&self, user: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+        pda::token_account(user, &self.lp_mint(mint), token_program)
+    }
+}
@@ -0,0 +1,45 @@
+//! 程序的全部 PDA，seeds 与 `transfer_contract::state` 中的常量保持一致
+
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use transfer_contract::state::{
+    CONFIG_SEED, LP_MINT_SEED, RECEIPT_SEED, RECEIVED_SEED, ROUTE_SEED, VAULT_SEED,
+};
+
+use crate::PROGRAM_ID;
+
+pub fn config(vault_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONFIG_SEED, &vault_id.to_be_bytes()], &PROGRAM_ID)
+}
+
+pub fn vault_authority(config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_SEED, config.as_ref()], &PROGRAM_ID)
+}
+
+pub fn mint_config(config: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONFIG_SEED, config.as_ref(), mint.as_ref()], &PROGRAM_ID)
+}
+
+pub fn lp_mint(config: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[LP_MINT_SEED, config.as_ref(), mint.as_ref()], &PROGRAM_ID)
+}
+
+pub fn route(config: &Pubkey, dst_eid: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ROUTE_SEED, config.as_ref(), &dst_eid.to_be_bytes()], &PROGRAM_ID)
+}
+
+pub fn receipt(config: &Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RECEIPT_SEED, config.as_ref(), &id.to_be_bytes()], &PROGRAM_ID)
+}
+
+pub fn received_message(config: &Pubkey, src_eid: u32, sender: &[u8; 32], nonce: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[RECEIVED_SEED, config.as_ref(), &src_eid.to_be_bytes(), sender, &nonce.to_be_bytes()],
+        &PROGRAM_ID,
+    )
+}
+
+/// owner 在 mint 下的 ATA，token_program 为 SPL Token 或 Token-2022
+pub fn token_account(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(owner, mint, token_program)
+}
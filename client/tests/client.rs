@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use anchor_lang::{Discriminator, InstructionData};
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022;
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use transfer_contract::instructions::deposit_from_user::{endpoint_pda, store_pda, DepositParams};
+use transfer_contract::instructions::transfer_out::TransferOutEvent;
+use transfer_contract::state::{Config, CONFIG_SEED};
+use transfer_contract_client::accounts::{decode, LpPosition};
+use transfer_contract_client::events::{parse_logs, VaultEvent};
+use transfer_contract_client::layerzero::{self, LayerZeroAccounts, DEFAULT_SEND_LIBRARY};
+use transfer_contract_client::{pda, Vault, PROGRAM_ID};
+
+fn config(vault: &Vault) -> Config {
+    Config {
+        admin: Pubkey::new_unique(),
+        allowed_caller_authority: Pubkey::new_unique(),
+        vault_authority_bump: pda::vault_authority(&vault.config).1,
+        allowed_callers: vec![],
+        pending_admin: Pubkey::default(),
+        guardian: Pubkey::default(),
+        paused: 0,
+        treasury: Pubkey::new_unique(),
+        remote_peers: vec![],
+        next_receipt_id: 7,
+        refund_timeout_secs: 3_600,
+        refund_fee_bps: 0,
+        vault_id: vault.vault_id,
+        bump: pda::config(vault.vault_id).1,
+        layerzero_oapp_program: layerzero::oapp_program(),
+        layerzero_endpoint_program: layerzero::endpoint_program(),
+        lz_store: store_pda(&layerzero::oapp_program()),
+        lz_endpoint: endpoint_pda(&layerzero::endpoint_program()),
+    }
+}
+
+#[test]
+fn vault_pdas_are_namespaced_by_vault_id() {
+    let vault = Vault::new(3);
+    let (expected, _) = Pubkey::find_program_address(&[CONFIG_SEED, &3u64.to_be_bytes()], &PROGRAM_ID);
+    assert_eq!(vault.config, expected);
+    assert_ne!(Vault::new(4).config, vault.config);
+
+    let mint = Pubkey::new_unique();
+    let user = Pubkey::new_unique();
+    // 同一个 mint 在两个 token program 下的 ATA 不同
+    assert_ne!(
+        vault.lp_position(&user, &mint, &spl_token::ID),
+        vault.lp_position(&user, &mint, &spl_token_2022::ID)
+    );
+}
+
+#[test]
+fn deposit_from_user_includes_layerzero_accounts() {
+    let vault = Vault::new(1);
+    let config = config(&vault);
+    let user = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let lz = LayerZeroAccounts::new(30101, &[9u8; 32], DEFAULT_SEND_LIBRARY);
+    let params = DepositParams {
+        amount: 1_000,
+        dst_eid: 30101,
+        dst_token: [1u8; 32],
+        merchant: [2u8; 32],
+        options: None,
+        native_fee: None,
+        lz_token_fee: None,
+    };
+    let ix = vault.deposit_from_user(&config, user, mint, spl_token::ID, params.clone(), &lz);
+
+    assert_eq!(ix.program_id, PROGRAM_ID);
+    assert_eq!(ix.data, transfer_contract::instruction::DepositFromUser { params: params.clone() }.data());
+    // 21 个具名账户（含 4 个 LZ token 可选账户）+ 5 个 endpoint::send 账户
+    assert_eq!(ix.accounts.len(), 26);
+    assert_eq!(ix.accounts[0].pubkey, vault.config);
+    assert!(ix.accounts[1].is_signer && ix.accounts[1].pubkey == user);
+    assert_eq!(ix.accounts[2].pubkey, vault.receipt(7));
+    assert_eq!(ix.accounts[6].pubkey, pda::token_account(&config.treasury, &mint, &spl_token::ID));
+    assert_eq!(ix.accounts[9].pubkey, vault.route(30101));
+    assert_eq!(ix.accounts[11].pubkey, lz.oapp_program);
+    assert_eq!(ix.accounts[12].pubkey, lz.peer);
+    assert_eq!(ix.accounts[13].pubkey, lz.store);
+    // 原生代币手续费转入 OApp 的 Store，Store 需可写；vault_authority 只签名
+    assert!(ix.accounts[13].is_writable);
+    assert!(ix.accounts[4].pubkey == vault.vault_authority && !ix.accounts[4].is_writable);
+    // 未用 LZ token 支付时可选账户以程序 ID 占位
+    assert!(ix.accounts[17..21].iter().all(|m| m.pubkey == PROGRAM_ID));
+    let nonce = ix.accounts.last().unwrap();
+    assert!(nonce.pubkey == lz.nonce && nonce.is_writable);
+
+    let lz_token = Pubkey::new_unique();
+    let lz = lz.with_lz_token(lz_token, spl_token::ID);
+    let ix = vault.deposit_from_user(&config, user, mint, spl_token::ID, params, &lz);
+    let keys: Vec<_> = ix.accounts[17..21].iter().map(|m| m.pubkey).collect();
+    assert_eq!(
+        keys,
+        [
+            lz_token,
+            pda::token_account(&user, &lz_token, &spl_token::ID),
+            pda::token_account(&vault.vault_authority, &lz_token, &spl_token::ID),
+            spl_token::ID,
+        ]
+    );
+    assert!(ix.accounts[18].is_writable && ix.accounts[19].is_writable);
+}
+
+#[test]
+fn quote_deposit_forwards_send_accounts() {
+    let vault = Vault::new(1);
+    let mint = Pubkey::new_unique();
+    let lz = LayerZeroAccounts::new(30101, &[9u8; 32], DEFAULT_SEND_LIBRARY);
+    let params = DepositParams {
+        amount: 1_000,
+        dst_eid: 30101,
+        dst_token: [1u8; 32],
+        merchant: [2u8; 32],
+        options: None,
+        native_fee: None,
+        lz_token_fee: None,
+    };
+    let ix = vault.quote_deposit(mint, params, &lz);
+
+    // 9 个具名账户 + 与 deposit 相同的 5 个 endpoint::send 账户
+    assert_eq!(ix.accounts.len(), 14);
+    assert_eq!(ix.accounts[9..], lz.remaining_accounts()[..]);
+}
+
+#[test]
+fn accounts_and_lp_positions_decode() {
+    let vault = Vault::new(1);
+    let config = config(&vault);
+    let mut data = Vec::new();
+    config.try_serialize(&mut data).unwrap();
+    let decoded: Config = decode(&data).unwrap();
+    assert_eq!(decoded.next_receipt_id, 7);
+    assert_eq!(decoded.treasury, config.treasury);
+
+    // 一半份额对应金库约一半余额（虚拟偏移向下取整）
+    let position = LpPosition::new(500_000, 1_000_000, 2_000);
+    assert_eq!(position.underlying, 999);
+}
+
+#[test]
+fn events_are_parsed_from_logs() {
+    let event = TransferOutEvent {
+        authority: Pubkey::new_unique(),
+        caller_roles: 1,
+        mint: Pubkey::new_unique(),
+        recipient_token_account: Pubkey::new_unique(),
+        amount: 42,
+    };
+    let mut data = TransferOutEvent::DISCRIMINATOR.to_vec();
+    event.serialize(&mut data).unwrap();
+    let logs = vec![
+        "Program log: Instruction: TransferOut".to_string(),
+        // 其它程序的事件：discriminator 不匹配
+        format!("Program data: {}", STANDARD.encode([0u8; 16])),
+        format!("Program data: {}", STANDARD.encode(&data)),
+    ];
+    let events = parse_logs(&logs);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].name(), "TransferOut");
+    match &events[0] {
+        VaultEvent::TransferOut(e) => assert!(e.amount == 42 && e.mint == event.mint),
+        _ => panic!("unexpected event"),
+    }
+}
@@ -0,0 +1,238 @@
+//! 本地测试用的 LayerZero OApp 桩程序。
+//!
+//! quote_send / relay_send 的 discriminator、参数与账户顺序按 layerzero_oapp.json 中的
+//! quoteSend / relaySend，Store / Peer PDA 的 seeds 与 transfer_contract 推导的相同；
+//! Store / Peer 账户只保留测试需要的字段，不调用 Endpoint，行为也不代表真实 OApp。
+//! quote_send 返回 Store 中配置的固定报价；relay_send 不发消息，从 Store 扣除原生代币手续费，
+//! 并以 RelaySent 事件记录调用；
+//! deliver 模拟 Executor 投递入站消息，以 Store PDA 签名调用 lz_receive / lz_receive_ack。
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+declare_id!("Gg2dU3zsaU8QmkNUgJG8LkrtV79Yj3BULasaym58RVxC");
+
+pub const STORE_SEED: &[u8] = b"Store";
+pub const PEER_SEED: &[u8] = b"Peer";
+
+#[program]
+pub mod mock_oapp {
+    use super::*;
+
+    pub fn init_store(ctx: Context<InitStore>, native_fee: u64, lz_token_fee: u64) -> Result<()> {
+        let store = &mut ctx.accounts.store;
+        store.admin = ctx.accounts.admin.key();
+        store.native_fee = native_fee;
+        store.lz_token_fee = lz_token_fee;
+        store.bump = ctx.bumps.store;
+        Ok(())
+    }
+
+    pub fn set_peer(ctx: Context<SetPeer>, dst_eid: u32, address: [u8; 32]) -> Result<()> {
+        let peer = &mut ctx.accounts.peer;
+        peer.address = address;
+        peer.bump = ctx.bumps.peer;
+        msg!("peer set: dst_eid={}", dst_eid);
+        Ok(())
+    }
+
+    pub fn quote_send(ctx: Context<QuoteSend>, params: QuoteSendParams) -> Result<MessagingFee> {
+        decode_message(&params.message)?;
+        require!(params.receiver == ctx.accounts.peer.address, MockOAppError::ReceiverMismatch);
+        let store = &ctx.accounts.store;
+        Ok(MessagingFee {
+            native_fee: store.native_fee,
+            lz_token_fee: if params.pay_in_lz_token { store.lz_token_fee } else { 0 },
+        })
+    }
+
+    /// 原生代币手续费从 Store 扣除，转给第一个可写的 remaining account（代替 Endpoint 的收费账户）；
+    /// 没有可写的 remaining account 时不扣费
+    pub fn relay_send<'info>(ctx: Context<'_, '_, '_, 'info, RelaySend<'info>>, params: RelaySendParams) -> Result<()> {
+        let message = decode_message(&params.message)?;
+        let fee_receiver = ctx.remaining_accounts.iter().find(|a| a.is_writable);
+        if let (Some(fee_receiver), true) = (fee_receiver, params.native_fee > 0) {
+            let store = ctx.accounts.store.to_account_info();
+            let rent = Rent::get()?.minimum_balance(store.data_len());
+            let available = store.lamports().saturating_sub(rent);
+            require!(available >= params.native_fee, MockOAppError::InsufficientFee);
+            **store.try_borrow_mut_lamports()? -= params.native_fee;
+            **fee_receiver.try_borrow_mut_lamports()? += params.native_fee;
+        }
+        emit!(RelaySent {
+            caller: ctx.accounts.caller.key(),
+            dst_eid: params.dst_eid,
+            receiver: ctx.accounts.peer.address,
+            message,
+            options: params.options,
+            native_fee: params.native_fee,
+            lz_token_fee: params.lz_token_fee,
+        });
+        Ok(())
+    }
+
+    /// 以 Store PDA 签名调用 receiver_program：data 为完整的指令数据（含 discriminator），
+    /// remaining accounts 原样作为被调用指令的账户，其中 Store 标记为 signer
+    pub fn deliver<'info>(ctx: Context<'_, '_, 'info, 'info, Deliver<'info>>, data: Vec<u8>) -> Result<()> {
+        let store = ctx.accounts.store.key();
+        let accounts = ctx
+            .remaining_accounts
+            .iter()
+            .map(|a| AccountMeta {
+                pubkey: a.key(),
+                is_signer: a.is_signer || a.key() == store,
+                is_writable: a.is_writable,
+            })
+            .collect();
+        let ix = Instruction { program_id: ctx.accounts.receiver_program.key(), accounts, data };
+        let seeds: &[&[u8]] = &[STORE_SEED, &[ctx.accounts.store.bump]];
+        invoke_signed(&ix, ctx.remaining_accounts, &[seeds])?;
+        Ok(())
+    }
+}
+
+/// message 以 "0x" 开头的 hex 字符串传入
+pub fn decode_message(message: &str) -> Result<Vec<u8>> {
+    let digits = message.strip_prefix("0x").ok_or(MockOAppError::InvalidMessage)?;
+    hex::decode(digits).map_err(|_| error!(MockOAppError::InvalidMessage))
+}
+
+#[account]
+pub struct Store {
+    pub admin: Pubkey,
+    /// quote_send 返回的固定报价
+    pub native_fee: u64,
+    pub lz_token_fee: u64,
+    pub bump: u8,
+}
+
+impl Store {
+    pub const SPACE: usize = 8 + 32 + 8 + 8 + 1;
+}
+
+/// 与真实 OApp 的 Peer 账户布局一致：discriminator 之后是 32 字节远端地址
+#[account]
+pub struct Peer {
+    pub address: [u8; 32],
+    pub bump: u8,
+}
+
+impl Peer {
+    pub const SPACE: usize = 8 + 32 + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct QuoteSendParams {
+    pub dst_eid: u32,
+    /// 远端 OApp 地址，须与 Peer 账户中的地址一致
+    pub receiver: [u8; 32],
+    pub message: String,
+    pub options: Vec<u8>,
+    pub pay_in_lz_token: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct RelaySendParams {
+    pub dst_eid: u32,
+    pub message: String,
+    pub options: Vec<u8>,
+    pub native_fee: u64,
+    pub lz_token_fee: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MessagingFee {
+    pub native_fee: u64,
+    pub lz_token_fee: u64,
+}
+
+#[derive(Accounts)]
+pub struct InitStore<'info> {
+    #[account(init, payer = admin, space = Store::SPACE, seeds = [STORE_SEED], bump)]
+    pub store: Account<'info, Store>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(dst_eid: u32)]
+pub struct SetPeer<'info> {
+    #[account(seeds = [STORE_SEED], bump = store.bump, has_one = admin)]
+    pub store: Account<'info, Store>,
+    #[account(
+        init,
+        payer = admin,
+        space = Peer::SPACE,
+        seeds = [PEER_SEED, store.key().as_ref(), dst_eid.to_be_bytes().as_ref()],
+        bump
+    )]
+    pub peer: Account<'info, Peer>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: QuoteSendParams)]
+pub struct QuoteSend<'info> {
+    #[account(seeds = [STORE_SEED], bump = store.bump)]
+    pub store: Account<'info, Store>,
+    #[account(
+        seeds = [PEER_SEED, store.key().as_ref(), params.dst_eid.to_be_bytes().as_ref()],
+        bump = peer.bump
+    )]
+    pub peer: Account<'info, Peer>,
+    /// CHECK: 桩程序不校验 endpoint
+    pub endpoint: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: RelaySendParams)]
+pub struct RelaySend<'info> {
+    #[account(
+        seeds = [PEER_SEED, store.key().as_ref(), params.dst_eid.to_be_bytes().as_ref()],
+        bump = peer.bump
+    )]
+    pub peer: Account<'info, Peer>,
+    /// 原生代币手续费从这里扣除；与 IDL 一样不声明 mut，调用方需以可写传入
+    #[account(seeds = [STORE_SEED], bump = store.bump)]
+    pub store: Account<'info, Store>,
+    /// CHECK: 桩程序不校验 endpoint
+    pub endpoint: UncheckedAccount<'info>,
+    /// 调用方（transfer_contract 的 vault_authority），只读、需签名
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Deliver<'info> {
+    #[account(seeds = [STORE_SEED], bump = store.bump)]
+    pub store: Account<'info, Store>,
+    /// CHECK: 接收消息的程序（transfer_contract）
+    #[account(executable)]
+    pub receiver_program: UncheckedAccount<'info>,
+}
+
+/// 一次 relay_send 调用的记录
+#[event]
+pub struct RelaySent {
+    pub caller: Pubkey,
+    pub dst_eid: u32,
+    /// Peer 账户中的远端地址
+    pub receiver: [u8; 32],
+    pub message: Vec<u8>,
+    pub options: Vec<u8>,
+    pub native_fee: u64,
+    pub lz_token_fee: u64,
+}
+
+#[error_code]
+pub enum MockOAppError {
+    #[msg("message must be a 0x-prefixed hex string")]
+    InvalidMessage,
+    #[msg("receiver does not match the peer address")]
+    ReceiverMismatch,
+    #[msg("store cannot cover the native fee")]
+    InsufficientFee,
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Config, ErrorCode, CONFIG_SEED};
+
+pub fn handler(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    require!(config.pending_admin != Pubkey::default(), ErrorCode::NoPendingAdmin);
+    // 必须由候选 admin 本人签名接受
+    require_keys_eq!(ctx.accounts.new_admin.key(), config.pending_admin, ErrorCode::NotAuthorized);
+
+    let previous_admin = config.admin;
+    config.admin = config.pending_admin;
+    config.pending_admin = Pubkey::default();
+
+    emit!(AdminAccepted {
+        previous_admin,
+        admin: config.admin,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(mut, seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    pub new_admin: Signer<'info>,
+}
+
+#[event]
+pub struct AdminAccepted {
+    pub previous_admin: Pubkey,
+    pub admin: Pubkey,
+}
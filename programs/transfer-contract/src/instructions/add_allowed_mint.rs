@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::state::token_ext::require_supported_mint;
+use crate::state::{Config, ErrorCode, MintConfig, MintConfigParams, CONFIG_SEED, LP_MINT_SEED, VAULT_SEED};
+
+pub fn handler(ctx: Context<AddAllowedMint>, params: MintConfigParams) -> Result<()> {
+    // only admin can update
+    require_keys_eq!(ctx.accounts.admin.key(), ctx.accounts.config.admin, ErrorCode::NotAuthorized);
+    require_supported_mint(&ctx.accounts.mint.to_account_info())?;
+
+    let mint_config = &mut ctx.accounts.mint_config;
+    // 已添加的 mint 要先 remove_allowed_mint 才能重新添加
+    require_keys_eq!(mint_config.mint, Pubkey::default(), ErrorCode::MintAlreadyAllowed);
+    mint_config.mint = ctx.accounts.mint.key();
+    mint_config.decimals = ctx.accounts.mint.decimals;
+    mint_config.outflow_window_start = 0;
+    mint_config.outflow_in_window = 0;
+    mint_config.lp_mint = ctx.accounts.lp_mint.key();
+    mint_config.bump = ctx.bumps.mint_config;
+    mint_config.paused = 0;
+    mint_config.apply(params)?;
+
+    emit!(AllowedMintAdded {
+        mint: mint_config.mint,
+        decimals: mint_config.decimals,
+        lp_mint: mint_config.lp_mint,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddAllowedMint<'info> {
+    #[account(seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    // init_if_needed 以便重复添加时报 MintAlreadyAllowed，而不是 system program 的 "already in use"
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = MintConfig::SPACE,
+        seeds = [CONFIG_SEED, config.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_SEED, config.key().as_ref()],
+        bump = config.vault_authority_bump
+    )]
+    /// CHECK: PDA used only as LP mint authority
+    pub vault_authority: UncheckedAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    // mint 被移除后 LP mint 无法关闭，再次添加时沿用
+    #[account(
+        init_if_needed,
+        payer = admin,
+        seeds = [LP_MINT_SEED, config.key().as_ref(), mint.key().as_ref()],
+        bump,
+        mint::decimals = mint.decimals,
+        mint::authority = vault_authority,
+        mint::token_program = token_program,
+    )]
+    pub lp_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct AllowedMintAdded {
+    pub mint: Pubkey,
+    pub decimals: u8,
+    pub lp_mint: Pubkey,
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Config, ErrorCode, CONFIG_SEED};
+
+pub fn handler(ctx: Context<CancelAdminProposal>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    // only admin can cancel
+    require_keys_eq!(ctx.accounts.admin.key(), config.admin, ErrorCode::NotAuthorized);
+    require!(config.pending_admin != Pubkey::default(), ErrorCode::NoPendingAdmin);
+
+    let cancelled = config.pending_admin;
+    config.pending_admin = Pubkey::default();
+
+    emit!(AdminProposalCancelled {
+        admin: config.admin,
+        cancelled_admin: cancelled,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelAdminProposal<'info> {
+    #[account(mut, seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct AdminProposalCancelled {
+    pub admin: Pubkey,
+    pub cancelled_admin: Pubkey,
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::state::{
+    Config, ErrorCode, MintConfig, PaymentReceipt, CONFIG_SEED, PAUSE_PAYOUTS, RECEIPT_SEED, VAULT_SEED,
+};
+
+/// 付款失败后，原用户从金库取回 net_amount（扣除退款手续费）
+pub fn handler(ctx: Context<ClaimRefund>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    config.require_not_paused(&ctx.accounts.mint_config, PAUSE_PAYOUTS)?;
+
+    let (amount, fee) = ctx.accounts.receipt.refund(config.refund_fee_bps)?;
+    ctx.accounts.mint_config.record_outflow(amount, Clock::get()?.unix_timestamp)?;
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.vault_token_account.to_account_info(),
+        to: ctx.accounts.user_token_account.to_account_info(),
+        authority: ctx.accounts.vault_authority.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+
+    let bump = config.vault_authority_bump;
+    let config_key = ctx.accounts.config.key();
+    let seeds: &[&[u8]] = &[VAULT_SEED, config_key.as_ref(), &[bump]];
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(cpi_program, cpi_accounts, &[seeds]),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    emit!(RefundClaimed {
+        receipt_id: ctx.accounts.receipt.id,
+        user: ctx.accounts.user.key(),
+        mint: ctx.accounts.mint.key(),
+        amount,
+        fee,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimRefund<'info> {
+    #[account(seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [RECEIPT_SEED, config.key().as_ref(), receipt.id.to_be_bytes().as_ref()],
+        bump = receipt.bump,
+        constraint = receipt.user == user.key() @ ErrorCode::NotAuthorized,
+    )]
+    pub receipt: Account<'info, PaymentReceipt>,
+
+    #[account(
+        seeds = [VAULT_SEED, config.key().as_ref()],
+        bump = config.vault_authority_bump
+    )]
+    /// CHECK: PDA used only as signing authority
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = user,
+        token::token_program = token_program,
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = receipt.mint @ ErrorCode::SourceMintMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED, config.key().as_ref(), mint.key().as_ref()],
+        bump = mint_config.bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct RefundClaimed {
+    pub receipt_id: u64,
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    /// 退款手续费，留在金库归 LP
+    pub fee: u64,
+}
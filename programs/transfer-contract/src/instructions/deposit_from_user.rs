@@ -1,28 +1,54 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token as token;
-use anchor_spl::token::{Mint, TokenAccount, Token, TransferChecked};
-use anchor_lang::solana_program::instruction::Instruction;
-use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::{get_return_data, invoke, invoke_signed};
+use solana_keccak_hasher as keccak;
 
-use crate::state::{Config, ErrorCode, CONFIG_SEED, VAULT_SEED};
+use crate::messaging::{self, evm_address, Message, Payout};
+use crate::options::validate_options;
+use crate::state::decimals::scale_amount;
+use crate::state::{
+    Config, DepositFees, ErrorCode, MintConfig, PaymentReceipt, PaymentStatus, Route, CONFIG_SEED, RECEIPT_SEED,
+    ROUTE_SEED, VAULT_SEED, PAUSE_CROSS_CHAIN, PAUSE_DEPOSITS,
+};
 
-// LayerZero 相关常量
-const LAYERZERO_OAPP_PROGRAM_ID: &str = "CV1qjq8phMMpxv62TExA9PpvTyZx58TNCqkFB2QQgJXH";
-const LAYERZERO_ENDPOINT_PROGRAM_ID: &str = "76y77prsiCMvXMjuoZ5VRrhG5qYBrUMYTE5WgHqgjEn6";
-const TAG_TOKEN_PAYOUT: u8 = 101;
-const PEER_SEED: &[u8] = b"Peer";
-const STORE_SEED: &[u8] = b"Store";
-const ENDPOINT_SEED: &[u8] = b"Endpoint";
+// initialize 写入 Config 的默认 LayerZero 程序（devnet），之后由 admin 通过 set_layerzero_programs 修改
+pub const LAYERZERO_OAPP_PROGRAM_ID: Pubkey = pubkey!("CV1qjq8phMMpxv62TExA9PpvTyZx58TNCqkFB2QQgJXH");
+pub const LAYERZERO_ENDPOINT_PROGRAM_ID: Pubkey = pubkey!("76y77prsiCMvXMjuoZ5VRrhG5qYBrUMYTE5WgHqgjEn6");
+/// OApp 的 Store PDA，lz_receive 用它确认调用方是 OApp
+pub const STORE_SEED: &[u8] = b"Store";
+pub const PEER_SEED: &[u8] = b"Peer";
+pub const ENDPOINT_SEED: &[u8] = b"Endpoint";
+
+/// OApp 的 Store PDA：[STORE_SEED]
+pub fn store_pda(oapp_program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[STORE_SEED], oapp_program).0
+}
+
+/// OApp 中 dst_eid 对应的 Peer PDA：[PEER_SEED, store, dst_eid]
+pub fn peer_pda(oapp_program: &Pubkey, dst_eid: u32) -> Pubkey {
+    let store = store_pda(oapp_program);
+    Pubkey::find_program_address(&[PEER_SEED, store.as_ref(), &dst_eid.to_be_bytes()], oapp_program).0
+}
+
+/// Endpoint 程序的全局 Endpoint PDA：[ENDPOINT_SEED]
+pub fn endpoint_pda(endpoint_program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[ENDPOINT_SEED], endpoint_program).0
+}
 
 pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, DepositFromUser<'info>>, params: DepositParams) -> Result<()> {
     let config = &ctx.accounts.config;
+    let receipt_id = config.next_receipt_id;
 
-    // 验证 mint 是否在允许列表中
-    require!(config.is_allowed_mint(&ctx.accounts.mint.key()), ErrorCode::MintNotAllowed);
+    // 入金会同时发起跨链消息，两个开关任一暂停都拒绝
+    config.require_not_paused(&ctx.accounts.mint_config, PAUSE_DEPOSITS | PAUSE_CROSS_CHAIN)?;
     require_keys_eq!(ctx.accounts.user_source_token.mint, ctx.accounts.mint.key(), ErrorCode::SourceMintMismatch);
     require_keys_eq!(ctx.accounts.vault_token_account.mint, ctx.accounts.mint.key(), ErrorCode::VaultMintMismatch);
 
-    // 执行代币转账：从用户账户到金库
+    // 用户的全部金额先转入金库；Token-2022 转账手续费会让金库少收，以余额差作为实际到账金额
+    let vault_before = ctx.accounts.vault_token_account.amount;
     let cpi_accounts = TransferChecked {
         from: ctx.accounts.user_source_token.to_account_info(),
         to: ctx.accounts.vault_token_account.to_account_info(),
@@ -30,178 +56,482 @@ pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, DepositFromUser<'info>>, p
         mint: ctx.accounts.mint.to_account_info(),
     };
     let cpi_program = ctx.accounts.token_program.to_account_info();
-    token::transfer_checked(
+    token_interface::transfer_checked(
         CpiContext::new(cpi_program, cpi_accounts),
         params.amount,
         ctx.accounts.mint.decimals,
     )?;
+    ctx.accounts.vault_token_account.reload()?;
+    let received = ctx
+        .accounts
+        .vault_token_account
+        .amount
+        .checked_sub(vault_before)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let transfer_fee = params.amount.checked_sub(received).ok_or(ErrorCode::MathOverflow)?;
 
-    // 编码跨链消息：模拟 EVM 的 abi.encode(TAG_TOKEN_PAYOUT, dst_token, merchant, amount)
-    let message = encode_evm_message(
-        TAG_TOKEN_PAYOUT,
-        &params.dst_token,
-        &params.merchant,
-        params.amount,
-    );
+    // 验证 mint 配置：是否启用、金额范围、目标链代币映射；手续费与跨链金额都按实际到账金额计算
+    let outbound =
+        build_deposit_message(&ctx.accounts.mint_config, &ctx.accounts.route, &params, received, receipt_id)?;
+    let fees = outbound.fees;
+
+    // 协议手续费：从金库转给 treasury（LP 手续费与未退还的 dust 留在金库）
+    if fees.protocol_fee > 0 {
+        transfer_from_vault(ctx.accounts, ctx.accounts.treasury_token_account.to_account_info(), fees.protocol_fee)?;
+    }
+    let dust_refund = outbound.dust_refund();
+    if dust_refund > 0 {
+        transfer_from_vault(ctx.accounts, ctx.accounts.user_source_token.to_account_info(), dust_refund)?;
+    }
 
     // 调用 LayerZero OApp 的 relay_send
-    // 如果前端没有传递这些参数，使用默认值
-    let options = params.options.unwrap_or_default(); // 空 Vec
-    let native_fee = params.native_fee.unwrap_or(1_000_000); //0.001 SOL
-    let lz_token_fee = params.lz_token_fee.unwrap_or(0);
-    
-    call_relay_send(
-        &ctx.accounts.layerzero_oapp_program,
-        &ctx.accounts.peer,
-        &ctx.accounts.store,
-        &ctx.accounts.endpoint,
-        &ctx.accounts.endpoint_program,
-        &ctx.accounts.vault_authority,
+    // 先按实际消息报价；前端未传手续费时直接使用报价，传了则不得低于报价
+    let oapp = OAppAccounts {
+        program: &ctx.accounts.layerzero_oapp_program,
+        peer: &ctx.accounts.peer,
+        store: &ctx.accounts.store,
+        endpoint: &ctx.accounts.endpoint,
+        endpoint_program: &ctx.accounts.endpoint_program,
+        remaining_accounts: ctx.remaining_accounts,
+    };
+    let quote = quote_relay_send(
+        &oapp,
         params.dst_eid,
-        message,
-        options,
+        &outbound.message,
+        &outbound.options,
+        params.lz_token_fee.is_some(),
+    )?;
+    // native_fee 只是上限，实际按报价收取；lz_token_fee 预付后退还未用完的部分
+    let native_fee = quote.native_fee;
+    let lz_token_fee = params.lz_token_fee.unwrap_or(quote.lz_token_fee);
+    require!(
+        params.native_fee.unwrap_or(native_fee) >= native_fee && lz_token_fee >= quote.lz_token_fee,
+        ErrorCode::FeeBelowQuote
+    );
+
+    // 原生代币手续费由用户承担：转入 OApp 的 Store，OApp 以 Store 签名向 Endpoint 支付，
+    // 发送后 Store 的余额必须回到转入前（见 check_native_fee_charged）
+    let store_before = ctx.accounts.store.lamports();
+    if native_fee > 0 {
+        let cpi_accounts = system_program::Transfer {
+            from: ctx.accounts.user.to_account_info(),
+            to: ctx.accounts.store.to_account_info(),
+        };
+        system_program::transfer(
+            CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts),
+            native_fee,
+        )?;
+    }
+    let lz_token_before = if lz_token_fee > 0 {
+        let lz_token = ctx.accounts.lz_token_accounts()?;
+        let before = lz_token.vault_account.amount;
+        let cpi_accounts = TransferChecked {
+            from: lz_token.user_account.to_account_info(),
+            to: lz_token.vault_account.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+            mint: lz_token.mint.to_account_info(),
+        };
+        token_interface::transfer_checked(
+            CpiContext::new(lz_token.token_program.to_account_info(), cpi_accounts),
+            lz_token_fee,
+            lz_token.mint.decimals,
+        )?;
+        Some(before)
+    } else {
+        None
+    };
+
+    let message_hash = keccak::hash(&outbound.message).to_bytes();
+    let lz_token_fee_account =
+        lz_token_before.and(ctx.accounts.vault_lz_token_account.as_ref()).map(|a| a.to_account_info());
+    let relay_params = RelaySendParams {
+        dst_eid: params.dst_eid,
+        message: messaging::to_hex(&outbound.message),
+        options: outbound.options,
         native_fee,
         lz_token_fee,
-        config.vault_authority_bump,
-        config.key(),
-    )?;
+    };
+    // 使用 vault_authority PDA 签名进行 CPI 调用
+    let config_key = config.key();
+    let seeds: &[&[u8]] = &[VAULT_SEED, config_key.as_ref(), &[config.vault_authority_bump]];
+    call_relay_send(&oapp, &ctx.accounts.vault_authority, lz_token_fee_account, &relay_params, seeds)?;
+
+    check_native_fee_charged(store_before, ctx.accounts.store.lamports())?;
+    // 退还未用完的 LZ token 手续费；OApp 多扣（金库代付）则整笔失败
+    let lz_token_refund = match lz_token_before {
+        Some(before) => {
+            let vault_account = ctx.accounts.vault_lz_token_account.as_mut().ok_or(ErrorCode::LzTokenAccountsMissing)?;
+            vault_account.reload()?;
+            let refund = unused_fee(lz_token_fee, before, vault_account.amount)?;
+            if refund > 0 {
+                refund_lz_token_fee(ctx.accounts, refund)?;
+            }
+            refund
+        }
+        None => 0,
+    };
+
+    // 记录回执，供对账与后续退款使用
+    let receipt = &mut ctx.accounts.receipt;
+    receipt.id = receipt_id;
+    receipt.user = ctx.accounts.user.key();
+    receipt.mint = ctx.accounts.mint.key();
+    receipt.amount = params.amount;
+    receipt.net_amount = fees.net_amount - outbound.dust;
+    receipt.scaled_amount = outbound.scaled_amount;
+    receipt.dst_eid = params.dst_eid;
+    receipt.dst_token = params.dst_token;
+    receipt.merchant = params.merchant;
+    receipt.message_hash = message_hash;
+    receipt.status = PaymentStatus::Sent;
+    receipt.created_at = Clock::get()?.unix_timestamp;
+    receipt.bump = ctx.bumps.receipt;
+
+    let config = &mut ctx.accounts.config;
+    config.next_receipt_id = receipt_id.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
 
     // 发出事件
     emit!(DepositEvent {
+        receipt_id,
         user: ctx.accounts.user.key(),
         mint: ctx.accounts.mint.key(),
         amount: params.amount,
+        transfer_fee,
+        protocol_fee: fees.protocol_fee,
+        lp_fee: fees.lp_fee,
+        net_amount: fees.net_amount,
+        dust: outbound.dust,
+        dust_refunded: outbound.refund_dust,
+        scaled_amount: outbound.scaled_amount,
+        dst_decimals: outbound.dst_decimals,
         dst_eid: params.dst_eid,
         dst_token: params.dst_token,
         merchant: params.merchant,
+        message_hash,
+        native_fee,
+        lz_token_fee: lz_token_fee - lz_token_refund,
     });
 
     Ok(())
 }
 
-/// 编码消息，模拟 Solidity 的 abi.encode(uint8, address, address, uint256)
-/// Solidity abi.encode 会将每个参数 padding 到 32 字节
-fn encode_evm_message(tag: u8, dst_token: &[u8; 32], merchant: &[u8; 32], amount: u64) -> Vec<u8> {
-    let mut message = Vec::with_capacity(128);
-    
-    // uint8 tag: padding 到 32 字节（左边补 0）
-    let mut tag_bytes = [0u8; 32];
-    tag_bytes[31] = tag;
-    message.extend_from_slice(&tag_bytes);
-    
-    // address dst_token: 32 字节（EVM 地址 20 字节，左边补 12 个 0）
-    message.extend_from_slice(dst_token);
-    
-    // address merchant: 32 字节（EVM 地址 20 字节，左边补 12 个 0）
-    message.extend_from_slice(merchant);
-    
-    // uint256 amount: 将 u64 转换为 32 字节大端序
-    let mut amount_bytes = [0u8; 32];
-    amount_bytes[24..32].copy_from_slice(&amount.to_be_bytes());
-    message.extend_from_slice(&amount_bytes);
-    
-    message
+/// LZ token 付款账户在 CPI 前后的余额变化：返回未用完、应退还给用户的部分（不超过 paid）。
+/// 发送后余额低于付款前，说明 OApp 扣得比用户支付的多，报 LayerZeroFeeExceeded
+pub fn unused_fee(paid: u64, balance_before: u64, balance_after: u64) -> Result<u64> {
+    let unused = balance_after.checked_sub(balance_before).ok_or(ErrorCode::LayerZeroFeeExceeded)?;
+    Ok(unused.min(paid))
 }
 
-/// 调用 LayerZero OApp 的 relay_send
-fn call_relay_send<'info>(
-    layerzero_oapp_program: &AccountInfo<'info>,
-    peer: &AccountInfo<'info>,
-    store: &AccountInfo<'info>,
-    endpoint: &AccountInfo<'info>,
-    endpoint_program: &AccountInfo<'info>,
-    vault_authority: &AccountInfo<'info>,
-    dst_eid: u32,
-    message: Vec<u8>,
-    options: Vec<u8>,
-    native_fee: u64,
-    lz_token_fee: u64,
-    vault_bump: u8,
-    config_key: Pubkey,
-) -> Result<()> {
-    // relay_send 的参数结构
-    #[derive(AnchorSerialize)]
-    struct RelaySendParams {
-        dst_eid: u32,
-        message: String,
-        options: Vec<u8>,
-        native_fee: u64,
-        lz_token_fee: u64,
+/// relay_send 之后 Store 的余额必须等于用户转入手续费之前：余额更高说明 OApp 没有扣走手续费
+/// （LayerZeroFeeNotCharged），更低说明 Store 替用户垫付了（LayerZeroFeeExceeded）
+pub fn check_native_fee_charged(store_before: u64, store_after: u64) -> Result<()> {
+    require!(store_after <= store_before, ErrorCode::LayerZeroFeeNotCharged);
+    require!(store_after >= store_before, ErrorCode::LayerZeroFeeExceeded);
+    Ok(())
+}
+
+/// 由 vault_authority 签名，把未用完的 LZ token 手续费退还给用户
+fn refund_lz_token_fee<'info>(accounts: &DepositFromUser<'info>, amount: u64) -> Result<()> {
+    let lz_token = accounts.lz_token_accounts()?;
+    let cpi_accounts = TransferChecked {
+        from: lz_token.vault_account.to_account_info(),
+        to: lz_token.user_account.to_account_info(),
+        authority: accounts.vault_authority.to_account_info(),
+        mint: lz_token.mint.to_account_info(),
+    };
+    let config_key = accounts.config.key();
+    let seeds: &[&[u8]] = &[VAULT_SEED, config_key.as_ref(), &[accounts.config.vault_authority_bump]];
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(lz_token.token_program.to_account_info(), cpi_accounts, &[seeds]),
+        amount,
+        lz_token.mint.decimals,
+    )
+}
+
+/// 用 LZ token 支付手续费时需要的账户
+struct LzTokenAccounts<'a, 'info> {
+    mint: &'a InterfaceAccount<'info, Mint>,
+    user_account: &'a InterfaceAccount<'info, TokenAccount>,
+    /// vault_authority 的 ATA，作为 relay_send 的 LZ token 付款账户
+    vault_account: &'a InterfaceAccount<'info, TokenAccount>,
+    token_program: &'a Interface<'info, TokenInterface>,
+}
+
+impl<'info> DepositFromUser<'info> {
+    fn lz_token_accounts(&self) -> Result<LzTokenAccounts<'_, 'info>> {
+        let (Some(mint), Some(user_account), Some(vault_account), Some(token_program)) = (
+            self.lz_token_mint.as_ref(),
+            self.user_lz_token_account.as_ref(),
+            self.vault_lz_token_account.as_ref(),
+            self.lz_token_program.as_ref(),
+        ) else {
+            return err!(ErrorCode::LzTokenAccountsMissing);
+        };
+        require_keys_eq!(user_account.mint, mint.key(), ErrorCode::InvalidLzTokenAccount);
+        let expected =
+            get_associated_token_address_with_program_id(&self.vault_authority.key(), &mint.key(), &token_program.key());
+        require_keys_eq!(vault_account.key(), expected, ErrorCode::InvalidLzTokenAccount);
+        Ok(LzTokenAccounts { mint, user_account, vault_account, token_program })
     }
+}
 
-    // 将字节转换为 hex 字符串（EVM 格式）
-    let message_hex = format!("0x{}", hex::encode(&message));
-    
-    let relay_params = RelaySendParams {
-        dst_eid,
-        message: message_hex,
+/// 入金校验 + 手续费拆分 + 跨链消息编码 + LayerZero options，quote_deposit 与 deposit_from_user 共用。
+/// amount 是金库实际到账的金额（扣除 Token-2022 转账手续费后）
+pub(crate) fn build_deposit_message(
+    mint_config: &MintConfig,
+    route: &Route,
+    params: &DepositParams,
+    amount: u64,
+    receipt_id: u64,
+) -> Result<OutboundDeposit> {
+    require!(mint_config.enabled, ErrorCode::MintDisabled);
+    mint_config.check_deposit(amount)?;
+    route.check_deposit(&mint_config.mint, &params.dst_token, &params.merchant, amount)?;
+    let fees = mint_config.deposit_fees(amount)?;
+    let dst = mint_config
+        .dst_token(params.dst_eid)
+        .filter(|t| t.token == params.dst_token)
+        .ok_or(ErrorCode::DstTokenNotAllowed)?;
+
+    // 目标链地址必须是合法的 EVM 地址（左边补 12 个 0）
+    evm_address(&params.dst_token)?;
+    evm_address(&params.merchant)?;
+
+    // 商户到账的是扣除手续费后的金额，按目标链代币的 decimals 换算
+    let (scaled_amount, dust) =
+        scale_amount(fees.net_amount, mint_config.decimals, dst.decimals).ok_or(ErrorCode::MathOverflow)?;
+    require!(scaled_amount > 0, ErrorCode::DepositBelowMinimum);
+
+    let message = Message::Payout(Payout {
+        receipt_id,
+        token: params.dst_token,
+        recipient: params.merchant,
+        amount: scaled_amount,
+    })
+    .encode();
+
+    // 客户端传入的 options 需合法且设置了 lzReceive gas；未传时按路由的 executor 配置生成
+    let options = match &params.options {
+        Some(options) => {
+            validate_options(options)?;
+            options.clone()
+        }
+        None => route.executor.options(&params.merchant),
+    };
+    Ok(OutboundDeposit {
+        fees,
+        dust,
+        refund_dust: mint_config.refund_dust,
+        scaled_amount,
+        dst_decimals: dst.decimals,
+        message,
         options,
-        native_fee,
-        lz_token_fee,
+    })
+}
+
+pub(crate) struct OutboundDeposit {
+    pub fees: DepositFees,
+    /// net_amount 换算到目标链 decimals 后截掉的源链单位
+    pub dust: u64,
+    pub refund_dust: bool,
+    pub scaled_amount: u128,
+    pub dst_decimals: u8,
+    pub message: Vec<u8>,
+    pub options: Vec<u8>,
+}
+
+impl OutboundDeposit {
+    /// 需要从金库退还给用户的 dust
+    pub fn dust_refund(&self) -> u64 {
+        if self.refund_dust { self.dust } else { 0 }
+    }
+}
+
+/// 由 vault_authority 签名，从金库转出
+fn transfer_from_vault<'info>(accounts: &DepositFromUser<'info>, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+    let cpi_accounts = TransferChecked {
+        from: accounts.vault_token_account.to_account_info(),
+        to,
+        authority: accounts.vault_authority.to_account_info(),
+        mint: accounts.mint.to_account_info(),
     };
+    let config_key = accounts.config.key();
+    let seeds: &[&[u8]] = &[VAULT_SEED, config_key.as_ref(), &[accounts.config.vault_authority_bump]];
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(accounts.token_program.to_account_info(), cpi_accounts, &[seeds]),
+        amount,
+        accounts.mint.decimals,
+    )
+}
 
-    // 序列化参数
-    let mut data = Vec::new();
-    // 添加 relay_send 的方法标识符（8 字节的 Anchor discriminator）
-    // 注意：这个值需要从 LayerZero OApp 的 IDL 中验证
-    let discriminator: [u8; 8] = [
-        152,242,87,43,84,188,143,155
-    ]; // sighash("global:relay_send")
-    data.extend_from_slice(&discriminator);
-    relay_params.serialize(&mut data)?;
-
-    // 构建账户列表 - 对应 RelaySend 结构
-    let account_metas = vec![
-        // peer - 需要验证的 PDA
-        anchor_lang::solana_program::instruction::AccountMeta::new_readonly(
-            peer.key(),
-            false,
-        ),
-        // store - 需要验证的 PDA
-        anchor_lang::solana_program::instruction::AccountMeta::new_readonly(
-            store.key(),
-            false,
-        ),
-        // endpoint - 跨程序 PDA
-        anchor_lang::solana_program::instruction::AccountMeta::new_readonly(
-            endpoint.key(),
-            false,
-        ),
-        // caller (vault_authority) - 作为签名者
-        anchor_lang::solana_program::instruction::AccountMeta::new_readonly(
-            vault_authority.key(),
-            true, // is_signer = true
-        ),
-        // 可能还需要 endpoint_program 和其他账户，具体看 relay_send 的实现
-        // 这里先只传递基本的 4 个账户
-    ];
+/// LayerZero 报价结果，与 OApp 的 MessagingFee 布局一致
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MessagingFee {
+    pub native_fee: u64,
+    pub lz_token_fee: u64,
+}
+
+/// OApp quoteSend 的参数（见 layerzero_oapp.json）；receiver 为 Peer 账户中的远端地址，
+/// message 与 relay_send 一样使用 hex 字符串
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct QuoteSendParams {
+    pub dst_eid: u32,
+    pub receiver: [u8; 32],
+    pub message: String,
+    pub options: Vec<u8>,
+    pub pay_in_lz_token: bool,
+}
+
+/// OApp relay_send 的参数
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct RelaySendParams {
+    pub dst_eid: u32,
+    pub message: String,
+    pub options: Vec<u8>,
+    pub native_fee: u64,
+    pub lz_token_fee: u64,
+}
+
+/// sighash("global:quote_send")
+pub const QUOTE_SEND_DISCRIMINATOR: [u8; 8] = [207, 0, 49, 214, 160, 211, 76, 211];
+/// sighash("global:relay_send")
+pub const RELAY_SEND_DISCRIMINATOR: [u8; 8] = [152, 242, 87, 43, 84, 188, 143, 155];
+
+/// Peer 账户布局：8 字节 discriminator 之后是 32 字节的远端地址
+pub fn peer_address(peer_account_data: &[u8]) -> Option<[u8; 32]> {
+    peer_account_data.get(8..40)?.try_into().ok()
+}
 
-    // 构建 CPI 指令
-    let ix = Instruction {
-        program_id: layerzero_oapp_program.key(),
-        accounts: account_metas,
+/// OApp quoteSend 指令：账户为 store、peer、endpoint，均只读
+pub fn quote_send_instruction(
+    oapp_program: Pubkey,
+    store: Pubkey,
+    peer: Pubkey,
+    endpoint: Pubkey,
+    params: &QuoteSendParams,
+) -> Result<Instruction> {
+    let mut data = QUOTE_SEND_DISCRIMINATOR.to_vec();
+    params.serialize(&mut data)?;
+    Ok(Instruction {
+        program_id: oapp_program,
+        accounts: vec![
+            AccountMeta::new_readonly(store, false),
+            AccountMeta::new_readonly(peer, false),
+            AccountMeta::new_readonly(endpoint, false),
+        ],
         data,
+    })
+}
+
+/// OApp relaySend 指令：账户为 peer、store、endpoint 以及 caller（vault_authority），
+/// caller 只读、需签名（见 layerzero_oapp.json）。store 额外以可写传入：
+/// 原生代币手续费已转入 Store，由 OApp 从 Store 扣除
+pub fn relay_send_instruction(
+    oapp_program: Pubkey,
+    peer: Pubkey,
+    store: Pubkey,
+    endpoint: Pubkey,
+    caller: Pubkey,
+    params: &RelaySendParams,
+) -> Result<Instruction> {
+    let mut data = RELAY_SEND_DISCRIMINATOR.to_vec();
+    params.serialize(&mut data)?;
+    Ok(Instruction {
+        program_id: oapp_program,
+        accounts: vec![
+            AccountMeta::new_readonly(peer, false),
+            AccountMeta::new(store, false),
+            AccountMeta::new_readonly(endpoint, false),
+            AccountMeta::new_readonly(caller, true),
+        ],
+        data,
+    })
+}
+
+/// quoteSend / relaySend CPI 用到的 OApp 账户：地址已由 DepositFromUser / QuoteDeposit 的约束校验，
+/// remaining_accounts 为指令的 remaining accounts（endpoint::send 需要的发送库、nonce 等账户）
+pub(crate) struct OAppAccounts<'a, 'info> {
+    pub program: &'a AccountInfo<'info>,
+    pub peer: &'a AccountInfo<'info>,
+    pub store: &'a AccountInfo<'info>,
+    pub endpoint: &'a AccountInfo<'info>,
+    pub endpoint_program: &'a AccountInfo<'info>,
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'info> OAppAccounts<'_, 'info> {
+    /// 追加 remaining accounts；不转发 signer 权限，这些账户只供 OApp 继续传给 Endpoint
+    fn push_remaining(&self, ix: &mut Instruction, account_infos: &mut Vec<AccountInfo<'info>>) {
+        for account in self.remaining_accounts {
+            ix.accounts.push(AccountMeta { pubkey: account.key(), is_signer: false, is_writable: account.is_writable });
+            account_infos.push(account.clone());
+        }
+    }
+}
+
+/// 调用 LayerZero OApp 的 quoteSend，通过 return data 取回报价；
+/// receiver 取自 Peer 账户，remaining accounts 原样转发（Endpoint 报价同样需要发送库账户）
+pub(crate) fn quote_relay_send(
+    oapp: &OAppAccounts,
+    dst_eid: u32,
+    message: &[u8],
+    options: &[u8],
+    pay_in_lz_token: bool,
+) -> Result<MessagingFee> {
+    let receiver = peer_address(&oapp.peer.try_borrow_data()?).ok_or(ErrorCode::InvalidPeer)?;
+    let params = QuoteSendParams {
+        dst_eid,
+        receiver,
+        message: messaging::to_hex(message),
+        options: options.to_vec(),
+        pay_in_lz_token,
     };
+    let mut ix =
+        quote_send_instruction(oapp.program.key(), oapp.store.key(), oapp.peer.key(), oapp.endpoint.key(), &params)?;
 
-    // 准备 account_infos
-    let account_infos = vec![
-        peer.clone(),
-        store.clone(),
-        endpoint.clone(),
-        vault_authority.clone(),
-        endpoint_program.clone(), // 可能需要
-    ];
+    let mut account_infos =
+        vec![oapp.store.clone(), oapp.peer.clone(), oapp.endpoint.clone(), oapp.endpoint_program.clone()];
+    oapp.push_remaining(&mut ix, &mut account_infos);
+    invoke(&ix, &account_infos)?;
 
-    // 使用 vault_authority PDA 签名进行 CPI 调用
-    let seeds: &[&[u8]] = &[VAULT_SEED, config_key.as_ref(), &[vault_bump]];
-    let signer_seeds = &[seeds];
-    
-    invoke_signed(
-        &ix,
-        &account_infos,
-        signer_seeds,
+    // return data 必须来自 OApp 程序本身
+    let (program_id, return_data) = get_return_data().ok_or(ErrorCode::InvalidQuote)?;
+    require_keys_eq!(program_id, oapp.program.key(), ErrorCode::InvalidQuote);
+    MessagingFee::try_from_slice(&return_data).map_err(|_| error!(ErrorCode::InvalidQuote))
+}
+
+/// 调用 LayerZero OApp 的 relay_send，由 vault_authority 以 signer_seeds 签名；
+/// 用 LZ token 支付时 lz_token_fee_account 追加在账户末尾，之后原样转发 remaining accounts
+fn call_relay_send<'info>(
+    oapp: &OAppAccounts<'_, 'info>,
+    vault_authority: &AccountInfo<'info>,
+    lz_token_fee_account: Option<AccountInfo<'info>>,
+    params: &RelaySendParams,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let mut ix = relay_send_instruction(
+        oapp.program.key(),
+        oapp.peer.key(),
+        oapp.store.key(),
+        oapp.endpoint.key(),
+        vault_authority.key(),
+        params,
     )?;
 
+    let mut account_infos = vec![
+        oapp.peer.clone(),
+        oapp.store.clone(),
+        oapp.endpoint.clone(),
+        vault_authority.clone(),
+        oapp.endpoint_program.clone(),
+    ];
+    if let Some(account) = lz_token_fee_account {
+        ix.accounts.push(AccountMeta::new(account.key(), false));
+        account_infos.push(account);
+    }
+    oapp.push_remaining(&mut ix, &mut account_infos);
+
+    invoke_signed(&ix, &account_infos, &[signer_seeds])?;
     Ok(())
 }
 
@@ -215,25 +545,35 @@ pub struct DepositParams {
     pub dst_token: [u8; 32],
     /// 商户地址（EVM 地址，32 字节，左边补 12 个 0）（必填）
     pub merchant: [u8; 32],
-    /// LayerZero 消息选项（可选，默认为空）
+    /// LayerZero type-3 options（可选，默认按路由的 executor 配置生成；传入时必须设置 lzReceive gas）
     pub options: Option<Vec<u8>>,
-    /// LayerZero 原生代币手续费（可选，默认为 0）
+    /// LayerZero 原生代币手续费上限（可选，不得低于 OApp 报价）；由用户按报价支付
     pub native_fee: Option<u64>,
-    /// LayerZero 代币手续费（可选，默认为 0）
+    /// LayerZero 代币手续费上限（可选，传入即表示用 LZ token 支付，不得低于报价，需传入 LZ token 账户）
     pub lz_token_fee: Option<u64>,
 }
 
 #[derive(Accounts)]
 #[instruction(params: DepositParams)]
 pub struct DepositFromUser<'info> {
-    #[account(seeds = [CONFIG_SEED], bump)]
+    #[account(mut, seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
     pub config: Account<'info, Config>,
 
     #[account(mut)]
     pub user: Signer<'info>,
 
+    // 编号取自 config.next_receipt_id；并发入金时后到的交易会因账户已存在而失败，重试即可
+    #[account(
+        init,
+        payer = user,
+        space = PaymentReceipt::SPACE,
+        seeds = [RECEIPT_SEED, config.key().as_ref(), config.next_receipt_id.to_be_bytes().as_ref()],
+        bump
+    )]
+    pub receipt: Account<'info, PaymentReceipt>,
+
     #[account(mut)]
-    pub user_source_token: Account<'info, TokenAccount>,
+    pub user_source_token: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         seeds = [VAULT_SEED, config.key().as_ref()],
@@ -242,39 +582,103 @@ pub struct DepositFromUser<'info> {
     /// CHECK: PDA used only as signing authority for both token transfers and LayerZero CPI
     pub vault_authority: UncheckedAccount<'info>,
 
-    #[account(mut)]
-    pub vault_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = config.treasury,
+        token::token_program = token_program,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [CONFIG_SEED, config.key().as_ref(), mint.key().as_ref()],
+        bump = mint_config.bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
 
-    pub mint: Account<'info, Mint>,
-    pub token_program: Program<'info, Token>,
+    #[account(
+        seeds = [ROUTE_SEED, config.key().as_ref(), params.dst_eid.to_be_bytes().as_ref()],
+        bump = route.bump
+    )]
+    pub route: Account<'info, Route>,
+    pub token_program: Interface<'info, TokenInterface>,
     
     // ===== LayerZero OApp 相关账户 =====
     
     /// CHECK: LayerZero OApp 程序
-    #[account(address = Pubkey::try_from(LAYERZERO_OAPP_PROGRAM_ID).unwrap())]
+    #[account(executable, address = config.layerzero_oapp_program @ ErrorCode::InvalidOAppProgram)]
     pub layerzero_oapp_program: UncheckedAccount<'info>,
     
-    /// CHECK: LayerZero Peer 配置 PDA - 由 LayerZero OApp 程序验证
+    /// CHECK: LayerZero Peer PDA，地址为路由缓存的 route.peer
+    #[account(address = route.peer @ ErrorCode::InvalidPeer)]
     pub peer: UncheckedAccount<'info>,
     
-    /// CHECK: LayerZero Store PDA - 由 LayerZero OApp 程序验证
+    /// CHECK: LayerZero Store PDA，set_layerzero_programs 时缓存于 config.lz_store；
+    /// 用户的原生代币手续费转入这里，由 OApp 在 relay_send 中支付给 Endpoint
+    #[account(mut, address = config.lz_store @ ErrorCode::InvalidStore)]
     pub store: UncheckedAccount<'info>,
     
-    /// CHECK: LayerZero Endpoint PDA - 由 Endpoint 程序验证
+    /// CHECK: LayerZero Endpoint PDA，set_layerzero_programs 时缓存于 config.lz_endpoint
+    #[account(address = config.lz_endpoint @ ErrorCode::InvalidEndpoint)]
     pub endpoint: UncheckedAccount<'info>,
     
     /// CHECK: LayerZero Endpoint 程序
+    #[account(executable, address = config.layerzero_endpoint_program @ ErrorCode::InvalidEndpointProgram)]
     pub endpoint_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    // ===== 用 LZ token 支付手续费时必填（params.lz_token_fee） =====
+
+    pub lz_token_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// 用户的 LZ token 账户，手续费从这里转出，未用完的部分退回这里
+    #[account(mut)]
+    pub user_lz_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// vault_authority 的 LZ token ATA，relay_send 从这里扣 LZ token 手续费
+    #[account(mut)]
+    pub vault_lz_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub lz_token_program: Option<Interface<'info, TokenInterface>>,
 }
 
 #[event]
 pub struct DepositEvent {
+    pub receipt_id: u64,
     pub user: Pubkey,
     pub mint: Pubkey,
+    /// 用户转出的总额
     pub amount: u64,
+    /// Token-2022 转账手续费，金库实际到账 amount - transfer_fee，后续手续费均按到账金额计算
+    pub transfer_fee: u64,
+    pub protocol_fee: u64,
+    pub lp_fee: u64,
+    /// 扣除手续费后的金额（源链 decimals）
+    pub net_amount: u64,
+    /// 换算时截掉的尾差（源链 decimals）；dust_refunded 为 true 时已从金库退还给用户
+    pub dust: u64,
+    pub dust_refunded: bool,
+    /// 编码进跨链消息的金额（目标链 decimals）
+    pub scaled_amount: u128,
+    pub dst_decimals: u8,
     pub dst_eid: u32,
     pub dst_token: [u8; 32],
     pub merchant: [u8; 32],
+    pub message_hash: [u8; 32],
+    /// 用户实际承担的 LayerZero 手续费：原生代币为报价，LZ token 已扣除退款
+    pub native_fee: u64,
+    pub lz_token_fee: u64,
 }
 
 
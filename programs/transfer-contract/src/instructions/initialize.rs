@@ -1,28 +1,41 @@
 use anchor_lang::prelude::*;
-use crate::state::{Config, ErrorCode, CONFIG_SEED, VAULT_SEED, MAX_ALLOWED_MINTS};
+use crate::instructions::deposit_from_user::{LAYERZERO_ENDPOINT_PROGRAM_ID, LAYERZERO_OAPP_PROGRAM_ID};
+use crate::state::{Config, CONFIG_SEED, DEFAULT_REFUND_TIMEOUT_SECS, VAULT_SEED};
 
 pub fn handler(
     ctx: Context<Initialize>,
+    vault_id: u64,
     allowed_caller_authority: Pubkey,
-    allowed_mints: Vec<Pubkey>,
 ) -> Result<()> {
     let config = &mut ctx.accounts.config;
+    config.vault_id = vault_id;
+    config.bump = ctx.bumps.config;
     config.admin = ctx.accounts.admin.key();
     config.allowed_caller_authority = allowed_caller_authority;
-    require!(allowed_mints.len() >= 1 && allowed_mints.len() <= MAX_ALLOWED_MINTS, ErrorCode::MintNotAllowed);
-    config.allowed_mints = allowed_mints;
     // Anchor 0.32: bumps is a generated struct with fields per account
     config.vault_authority_bump = ctx.bumps.vault_authority;
+    config.allowed_callers = Vec::new();
+    config.pending_admin = Pubkey::default();
+    config.guardian = Pubkey::default();
+    config.paused = 0;
+    config.treasury = config.admin;
+    config.remote_peers = Vec::new();
+    config.next_receipt_id = 0;
+    config.refund_timeout_secs = DEFAULT_REFUND_TIMEOUT_SECS;
+    config.refund_fee_bps = 0;
+    config.set_layerzero_programs(LAYERZERO_OAPP_PROGRAM_ID, LAYERZERO_ENDPOINT_PROGRAM_ID);
     Ok(())
 }
 
 #[derive(Accounts)]
+#[instruction(vault_id: u64)]
 pub struct Initialize<'info> {
+    // 同一程序下可以有多个金库，各自的 config 由 vault_id 区分，其余 PDA 都挂在 config 之下
     #[account(
         init,
         payer = admin,
-        space = 8 + 32 + 32 + 4 + (32 * MAX_ALLOWED_MINTS) + 1,
-        seeds = [CONFIG_SEED],
+        space = Config::SPACE,
+        seeds = [CONFIG_SEED, vault_id.to_be_bytes().as_ref()],
         bump
     )]
     pub config: Account<'info, Config>,
@@ -39,4 +52,3 @@ pub struct Initialize<'info> {
     pub system_program: Program<'info, System>,
 }
 
-
@@ -1,17 +1,20 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token as token;
-use anchor_spl::token::{Mint, TokenAccount, Token, TransferChecked};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked};
 
-use crate::state::{Config, LiquidityPosition, ErrorCode, CONFIG_SEED, VAULT_SEED, LP_SEED};
+use crate::state::shares::shares_for_deposit;
+use crate::state::{Config, MintConfig, ErrorCode, CONFIG_SEED, VAULT_SEED, LP_MINT_SEED, PAUSE_DEPOSITS};
 
 pub fn handler(ctx: Context<LpDeposit>, amount: u64) -> Result<()> {
     let config = &ctx.accounts.config;
 
-    require!(config.is_allowed_mint(&ctx.accounts.mint.key()), ErrorCode::MintNotAllowed);
+    config.require_not_paused(&ctx.accounts.mint_config, PAUSE_DEPOSITS)?;
+    require!(ctx.accounts.mint_config.enabled, ErrorCode::MintDisabled);
     require_keys_eq!(ctx.accounts.user_source_token.mint, ctx.accounts.mint.key(), ErrorCode::SourceMintMismatch);
     require_keys_eq!(ctx.accounts.vault_token_account.mint, ctx.accounts.mint.key(), ErrorCode::VaultMintMismatch);
 
-    // transfer user -> vault
+    // transfer user -> vault；Token-2022 转账手续费会让金库少收，以余额差作为实际存入金额
+    let vault_before = ctx.accounts.vault_token_account.amount;
     let cpi_accounts = TransferChecked {
         from: ctx.accounts.user_source_token.to_account_info(),
         to: ctx.accounts.vault_token_account.to_account_info(),
@@ -19,55 +22,112 @@ pub fn handler(ctx: Context<LpDeposit>, amount: u64) -> Result<()> {
         mint: ctx.accounts.mint.to_account_info(),
     };
     let cpi_program = ctx.accounts.token_program.to_account_info();
-    token::transfer_checked(
+    token_interface::transfer_checked(
         CpiContext::new(cpi_program, cpi_accounts),
         amount,
         ctx.accounts.mint.decimals,
     )?;
+    ctx.accounts.vault_token_account.reload()?;
+    let received = ctx
+        .accounts
+        .vault_token_account
+        .amount
+        .checked_sub(vault_before)
+        .ok_or(ErrorCode::MathOverflow)?;
 
-    // update LP position
-    let lp = &mut ctx.accounts.liquidity_position;
-    if lp.amount == 0 {
-        lp.owner = ctx.accounts.user.key();
-        lp.mint = ctx.accounts.mint.key();
-    }
-    lp.amount = lp.amount.saturating_add(amount);
+    // 按存入前的金库总资产计算份额
+    let shares = shares_for_deposit(received, vault_before, ctx.accounts.lp_mint.supply)
+        .ok_or(ErrorCode::MathOverflow)?;
+    require!(shares > 0, ErrorCode::ZeroShares);
+
+    // mint LP shares to user using vault_authority signer
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.lp_mint.to_account_info(),
+        to: ctx.accounts.user_lp_token.to_account_info(),
+        authority: ctx.accounts.vault_authority.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+
+    let bump = config.vault_authority_bump;
+    let config_key = config.key();
+    let seeds: &[&[u8]] = &[VAULT_SEED, config_key.as_ref(), &[bump]];
+    token_interface::mint_to(
+        CpiContext::new_with_signer(cpi_program, cpi_accounts, &[seeds]),
+        shares,
+    )?;
+
+    emit!(LpDepositEvent {
+        user: ctx.accounts.user.key(),
+        mint: ctx.accounts.mint.key(),
+        amount,
+        received,
+        shares,
+    });
     Ok(())
 }
 
 #[derive(Accounts)]
 pub struct LpDeposit<'info> {
-    #[account(seeds = [CONFIG_SEED], bump)]
+    #[account(seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
     pub config: Account<'info, Config>,
 
     #[account(mut)]
     pub user: Signer<'info>,
 
-    #[account(
-        init_if_needed,
-        payer = user,
-        space = 8 + 32 + 32 + 8,
-        seeds = [LP_SEED, user.key().as_ref(), mint.key().as_ref()],
-        bump
-    )]
-    pub liquidity_position: Account<'info, LiquidityPosition>,
-
     #[account(mut)]
-    pub user_source_token: Account<'info, TokenAccount>,
+    pub user_source_token: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         seeds = [VAULT_SEED, config.key().as_ref()],
         bump = config.vault_authority_bump
     )]
-    /// CHECK: PDA used only as signing authority
+    /// CHECK: PDA used as vault owner and LP mint authority
     pub vault_authority: UncheckedAccount<'info>,
 
-    #[account(mut)]
-    pub vault_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    pub mint: Account<'info, Mint>,
-    pub token_program: Program<'info, Token>,
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [CONFIG_SEED, config.key().as_ref(), mint.key().as_ref()],
+        bump = mint_config.bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+
+    #[account(
+        mut,
+        address = mint_config.lp_mint,
+        seeds = [LP_MINT_SEED, config.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub lp_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = lp_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_lp_token: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-
+#[event]
+pub struct LpDepositEvent {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    /// 金库实际到账金额（扣除 Token-2022 转账手续费）
+    pub received: u64,
+    pub shares: u64,
+}
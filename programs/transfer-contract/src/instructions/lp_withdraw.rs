@@ -1,20 +1,39 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token as token;
-use anchor_spl::token::{Mint, TokenAccount, Token, TransferChecked};
+use anchor_spl::token_interface::{self, Burn, Mint, TokenAccount, TokenInterface, TransferChecked};
 
-use crate::state::{Config, LiquidityPosition, ErrorCode, CONFIG_SEED, VAULT_SEED, LP_SEED};
+use crate::state::shares::assets_for_shares;
+use crate::state::{Config, MintConfig, ErrorCode, CONFIG_SEED, VAULT_SEED, LP_MINT_SEED, PAUSE_LP_WITHDRAWALS};
 
-pub fn handler(ctx: Context<LpWithdraw>, amount: u64) -> Result<()> {
+pub fn handler(ctx: Context<LpWithdraw>, shares: u64) -> Result<()> {
     let config = &ctx.accounts.config;
 
-    require!(config.is_allowed_mint(&ctx.accounts.mint.key()), ErrorCode::MintNotAllowed);
+    config.require_not_paused(&ctx.accounts.mint_config, PAUSE_LP_WITHDRAWALS)?;
     require_keys_eq!(ctx.accounts.user_destination_token.mint, ctx.accounts.mint.key(), ErrorCode::RecipientMintMismatch);
     require_keys_eq!(ctx.accounts.vault_token_account.mint, ctx.accounts.mint.key(), ErrorCode::VaultMintMismatch);
 
     // check LP balance
-    let lp = &mut ctx.accounts.liquidity_position;
-    require!(lp.amount >= amount, ErrorCode::InsufficientLiquidity);
-    lp.amount = lp.amount - amount;
+    require!(ctx.accounts.user_lp_token.amount >= shares, ErrorCode::InsufficientLiquidity);
+
+    // 按销毁前的金库总资产计算可取回的数量
+    let amount = assets_for_shares(
+        shares,
+        ctx.accounts.vault_token_account.amount,
+        ctx.accounts.lp_mint.supply,
+    )
+    .ok_or(ErrorCode::MathOverflow)?;
+    require!(amount > 0, ErrorCode::ZeroShares);
+
+    // mint 被禁用时仍允许 LP 赎回，但受每日流出上限约束
+    ctx.accounts.mint_config.record_outflow(amount, Clock::get()?.unix_timestamp)?;
+
+    // burn LP shares (user signs)
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.lp_mint.to_account_info(),
+        from: ctx.accounts.user_lp_token.to_account_info(),
+        authority: ctx.accounts.user.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    token_interface::burn(CpiContext::new(cpi_program, cpi_accounts), shares)?;
 
     // transfer vault -> user using vault_authority signer
     let cpi_accounts = TransferChecked {
@@ -26,30 +45,31 @@ pub fn handler(ctx: Context<LpWithdraw>, amount: u64) -> Result<()> {
     let cpi_program = ctx.accounts.token_program.to_account_info();
 
     let bump = config.vault_authority_bump;
-    let config_key = ctx.accounts.config.key();
+    let config_key = config.key();
     let seeds: &[&[u8]] = &[VAULT_SEED, config_key.as_ref(), &[bump]];
-    token::transfer_checked(
+    token_interface::transfer_checked(
         CpiContext::new_with_signer(cpi_program, cpi_accounts, &[seeds]),
         amount,
         ctx.accounts.mint.decimals,
-    )
+    )?;
+
+    emit!(LpWithdrawEvent {
+        user: ctx.accounts.user.key(),
+        mint: ctx.accounts.mint.key(),
+        shares,
+        amount,
+    });
+    Ok(())
 }
 
 #[derive(Accounts)]
 pub struct LpWithdraw<'info> {
-    #[account(seeds = [CONFIG_SEED], bump)]
+    #[account(seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
     pub config: Account<'info, Config>,
 
     #[account(mut)]
     pub user: Signer<'info>,
 
-    #[account(
-        mut,
-        seeds = [LP_SEED, user.key().as_ref(), mint.key().as_ref()],
-        bump
-    )]
-    pub liquidity_position: Account<'info, LiquidityPosition>,
-
     #[account(
         seeds = [VAULT_SEED, config.key().as_ref()],
         bump = config.vault_authority_bump
@@ -57,14 +77,50 @@ pub struct LpWithdraw<'info> {
     /// CHECK: PDA used only as signing authority
     pub vault_authority: UncheckedAccount<'info>,
 
-    #[account(mut)]
-    pub vault_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(mut)]
-    pub user_destination_token: Account<'info, TokenAccount>,
+    pub user_destination_token: InterfaceAccount<'info, TokenAccount>,
 
-    pub mint: Account<'info, Mint>,
-    pub token_program: Program<'info, Token>,
-}
+    pub mint: InterfaceAccount<'info, Mint>,
 
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED, config.key().as_ref(), mint.key().as_ref()],
+        bump = mint_config.bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
 
+    #[account(
+        mut,
+        address = mint_config.lp_mint,
+        seeds = [LP_MINT_SEED, config.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub lp_mint: InterfaceAccount<'info, Mint>,
+
+    // LP 份额可自由转让，任何 owner = user 的 LP 代币账户都可以赎回
+    #[account(
+        mut,
+        token::mint = lp_mint,
+        token::authority = user,
+        token::token_program = token_program,
+    )]
+    pub user_lp_token: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct LpWithdrawEvent {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub shares: u64,
+    pub amount: u64,
+}
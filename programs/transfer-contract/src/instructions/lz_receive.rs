@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::instructions::deposit_from_user::STORE_SEED;
+use crate::messaging::Message;
+use crate::state::{
+    Config, ErrorCode, MintConfig, ReceivedMessage, CONFIG_SEED, PAUSE_CROSS_CHAIN, PAUSE_PAYOUTS, RECEIVED_SEED,
+    VAULT_SEED,
+};
+
+pub fn handler(ctx: Context<LzReceive>, params: LzReceiveParams) -> Result<()> {
+    let config = &ctx.accounts.config;
+
+    // 只接受已登记的源链 OApp 发来的消息
+    config.require_remote_peer(params.src_eid, &params.sender)?;
+    config.require_not_paused(&ctx.accounts.mint_config, PAUSE_PAYOUTS | PAUSE_CROSS_CHAIN)?;
+    require!(ctx.accounts.mint_config.enabled, ErrorCode::MintDisabled);
+
+    // 入站 payout 中 token / recipient 为 Solana 的 mint 与收款人钱包，receipt_id 为源链侧编号
+    let payout = match Message::decode(&params.message)? {
+        Message::Payout(payout) => payout,
+        _ => return err!(ErrorCode::InvalidPayload),
+    };
+    // 入站金额已按该 mint 的 decimals 计价，必须能放进 u64
+    let amount = u64::try_from(payout.amount).map_err(|_| error!(ErrorCode::InvalidPayload))?;
+    require!(amount > 0, ErrorCode::InvalidPayload);
+    let mint = Pubkey::from(payout.token);
+    let recipient = Pubkey::from(payout.recipient);
+    require_keys_eq!(mint, ctx.accounts.mint.key(), ErrorCode::PayloadMismatch);
+    require_keys_eq!(recipient, ctx.accounts.recipient.key(), ErrorCode::PayloadMismatch);
+
+    ctx.accounts.mint_config.record_outflow(amount, Clock::get()?.unix_timestamp)?;
+
+    // 去重记录：同一 (src_eid, sender, nonce) 再次到达时 init 会失败
+    let received = &mut ctx.accounts.received_message;
+    received.guid = params.guid;
+    received.bump = ctx.bumps.received_message;
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.vault_token_account.to_account_info(),
+        to: ctx.accounts.recipient_token_account.to_account_info(),
+        authority: ctx.accounts.vault_authority.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+
+    let bump = config.vault_authority_bump;
+    let config_key = ctx.accounts.config.key();
+    let seeds: &[&[u8]] = &[VAULT_SEED, config_key.as_ref(), &[bump]];
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(cpi_program, cpi_accounts, &[seeds]),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    emit!(LzReceiveEvent {
+        src_eid: params.src_eid,
+        sender: params.sender,
+        nonce: params.nonce,
+        guid: params.guid,
+        mint,
+        recipient,
+        amount,
+    });
+
+    Ok(())
+}
+
+/// 与 LayerZero OApp 的 LzReceiveParams 保持一致
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LzReceiveParams {
+    pub src_eid: u32,
+    /// 源链 OApp 地址（EVM 地址，32 字节，左边补 12 个 0）
+    pub sender: [u8; 32],
+    pub nonce: u64,
+    pub guid: [u8; 32],
+    pub message: Vec<u8>,
+    pub extra_data: Vec<u8>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: LzReceiveParams)]
+pub struct LzReceive<'info> {
+    #[account(seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: LayerZero OApp 程序
+    #[account(executable, address = config.layerzero_oapp_program @ ErrorCode::InvalidOAppProgram)]
+    pub layerzero_oapp_program: UncheckedAccount<'info>,
+
+    /// OApp 的 Store PDA，只有 OApp 程序能以它的身份签名
+    #[account(
+        seeds = [STORE_SEED],
+        bump,
+        seeds::program = layerzero_oapp_program.key(),
+    )]
+    pub oapp_store: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ReceivedMessage::SPACE,
+        seeds = [
+            RECEIVED_SEED,
+            config.key().as_ref(),
+            params.src_eid.to_be_bytes().as_ref(),
+            params.sender.as_ref(),
+            params.nonce.to_be_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub received_message: Account<'info, ReceivedMessage>,
+
+    #[account(
+        seeds = [VAULT_SEED, config.key().as_ref()],
+        bump = config.vault_authority_bump
+    )]
+    /// CHECK: PDA used only as signing authority
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: 收款人钱包，必须与消息中的 recipient 一致
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED, config.key().as_ref(), mint.key().as_ref()],
+        bump = mint_config.bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct LzReceiveEvent {
+    pub src_eid: u32,
+    pub sender: [u8; 32],
+    pub nonce: u64,
+    pub guid: [u8; 32],
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::deposit_from_user::STORE_SEED;
+use crate::instructions::lz_receive::LzReceiveParams;
+use crate::instructions::mark_payment_failed::PaymentStatusUpdated;
+use crate::messaging::Message;
+use crate::state::{Config, ErrorCode, PaymentReceipt, ReceivedMessage, CONFIG_SEED, RECEIPT_SEED, RECEIVED_SEED};
+
+/// 目标链对一笔出站付款的 ack / nack，更新 PaymentReceipt 状态
+pub fn handler(ctx: Context<LzReceiveAck>, params: LzReceiveParams) -> Result<()> {
+    ctx.accounts.config.require_remote_peer(params.src_eid, &params.sender)?;
+
+    let ack = match Message::decode(&params.message)? {
+        Message::Ack(ack) => ack,
+        _ => return err!(ErrorCode::InvalidPayload),
+    };
+    let receipt = &mut ctx.accounts.receipt;
+    require!(receipt.id == ack.receipt_id, ErrorCode::PayloadMismatch);
+    // ack 必须来自付款发往的那条链
+    require!(receipt.dst_eid == params.src_eid, ErrorCode::PayloadMismatch);
+    receipt.acknowledge(ack.success)?;
+
+    // 去重记录：同一 (src_eid, sender, nonce) 再次到达时 init 会失败
+    let received = &mut ctx.accounts.received_message;
+    received.guid = params.guid;
+    received.bump = ctx.bumps.received_message;
+
+    emit!(PaymentStatusUpdated {
+        receipt_id: receipt.id,
+        status: receipt.status,
+        authority: ctx.accounts.oapp_store.key(),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: LzReceiveParams)]
+pub struct LzReceiveAck<'info> {
+    #[account(seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: LayerZero OApp 程序
+    #[account(executable, address = config.layerzero_oapp_program @ ErrorCode::InvalidOAppProgram)]
+    pub layerzero_oapp_program: UncheckedAccount<'info>,
+
+    /// OApp 的 Store PDA，只有 OApp 程序能以它的身份签名
+    #[account(
+        seeds = [STORE_SEED],
+        bump,
+        seeds::program = layerzero_oapp_program.key(),
+    )]
+    pub oapp_store: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ReceivedMessage::SPACE,
+        seeds = [
+            RECEIVED_SEED,
+            config.key().as_ref(),
+            params.src_eid.to_be_bytes().as_ref(),
+            params.sender.as_ref(),
+            params.nonce.to_be_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub received_message: Account<'info, ReceivedMessage>,
+
+    // 编号在消息里，handler 中与 ack.receipt_id 比对；seeds 保证回执属于本金库
+    #[account(
+        mut,
+        seeds = [RECEIPT_SEED, config.key().as_ref(), receipt.id.to_be_bytes().as_ref()],
+        bump = receipt.bump
+    )]
+    pub receipt: Account<'info, PaymentReceipt>,
+
+    pub system_program: Program<'info, System>,
+}
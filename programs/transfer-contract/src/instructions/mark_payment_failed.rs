@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Config, ErrorCode, PaymentReceipt, PaymentStatus, CONFIG_SEED, RECEIPT_SEED};
+
+/// admin 证明：付款超时仍未收到目标链回执，判定失败以便用户退款
+pub fn handler(ctx: Context<MarkPaymentFailed>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    require_keys_eq!(ctx.accounts.admin.key(), config.admin, ErrorCode::NotAuthorized);
+
+    let receipt = &mut ctx.accounts.receipt;
+    receipt.expire(Clock::get()?.unix_timestamp, config.refund_timeout_secs)?;
+
+    emit!(PaymentStatusUpdated {
+        receipt_id: receipt.id,
+        status: receipt.status,
+        authority: ctx.accounts.admin.key(),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MarkPaymentFailed<'info> {
+    #[account(seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [RECEIPT_SEED, config.key().as_ref(), receipt.id.to_be_bytes().as_ref()],
+        bump = receipt.bump
+    )]
+    pub receipt: Account<'info, PaymentReceipt>,
+}
+
+/// 回执状态变更：目标链 ack（authority 为 OApp Store）或 admin 超时判定
+#[event]
+pub struct PaymentStatusUpdated {
+    pub receipt_id: u64,
+    pub status: PaymentStatus,
+    pub authority: Pubkey,
+}
@@ -4,5 +4,26 @@ pub mod transfer_out;
 pub mod lp_deposit;
 pub mod lp_withdraw;
 pub mod set_allowed_caller;
-
-
+pub mod set_caller_roles;
+pub mod add_allowed_mint;
+pub mod remove_allowed_mint;
+pub mod update_mint_config;
+pub mod propose_admin;
+pub mod accept_admin;
+pub mod cancel_admin_proposal;
+pub mod set_guardian;
+pub mod pause;
+pub mod unpause;
+pub mod pause_mint;
+pub mod unpause_mint;
+pub mod set_treasury;
+pub mod set_remote_peer;
+pub mod lz_receive;
+pub mod quote_deposit;
+pub mod set_route;
+pub mod set_refund_policy;
+pub mod mark_payment_failed;
+pub mod lz_receive_ack;
+pub mod claim_refund;
+pub mod set_layerzero_programs;
+pub mod refresh_route_peer;
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::state::pause::validate_pause_flags;
+use crate::state::{Config, ErrorCode, CONFIG_SEED};
+
+pub fn handler(ctx: Context<Pause>, flags: u8) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    // admin 或 guardian 都可以暂停
+    require!(config.can_pause(&ctx.accounts.authority.key()), ErrorCode::NotAuthorized);
+    validate_pause_flags(flags)?;
+    config.paused |= flags;
+
+    emit!(PauseUpdated {
+        mint: None,
+        authority: ctx.accounts.authority.key(),
+        paused: config.paused,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    #[account(mut, seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    pub authority: Signer<'info>,
+}
+
+/// pause / unpause / pause_mint / unpause_mint 共用
+#[event]
+pub struct PauseUpdated {
+    /// None 表示全局开关
+    pub mint: Option<Pubkey>,
+    pub authority: Pubkey,
+    /// 更新后的完整开关位
+    pub paused: u8,
+}
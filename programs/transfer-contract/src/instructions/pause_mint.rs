@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::instructions::pause::PauseUpdated;
+use crate::state::pause::validate_pause_flags;
+use crate::state::{Config, ErrorCode, MintConfig, CONFIG_SEED};
+
+pub fn handler(ctx: Context<PauseMint>, flags: u8) -> Result<()> {
+    // admin 或 guardian 都可以暂停
+    require!(ctx.accounts.config.can_pause(&ctx.accounts.authority.key()), ErrorCode::NotAuthorized);
+    validate_pause_flags(flags)?;
+    let mint_config = &mut ctx.accounts.mint_config;
+    mint_config.paused |= flags;
+
+    emit!(PauseUpdated {
+        mint: Some(mint_config.mint),
+        authority: ctx.accounts.authority.key(),
+        paused: mint_config.paused,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PauseMint<'info> {
+    #[account(seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED, config.key().as_ref(), mint.key().as_ref()],
+        bump = mint_config.bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+
+    pub authority: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+}
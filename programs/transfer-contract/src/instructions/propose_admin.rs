@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Config, ErrorCode, CONFIG_SEED};
+
+pub fn handler(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    // only admin can propose
+    require_keys_eq!(ctx.accounts.admin.key(), config.admin, ErrorCode::NotAuthorized);
+    require!(
+        new_admin != Pubkey::default() && new_admin != config.admin,
+        ErrorCode::InvalidPendingAdmin
+    );
+    // 再次提议会覆盖之前未被接受的提议
+    config.pending_admin = new_admin;
+
+    emit!(AdminProposed {
+        admin: config.admin,
+        pending_admin: new_admin,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    #[account(mut, seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct AdminProposed {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::instructions::deposit_from_user::{
+    build_deposit_message, quote_relay_send, DepositParams, MessagingFee, OAppAccounts,
+};
+use crate::state::token_ext::transfer_fee;
+use crate::state::{Config, ErrorCode, MintConfig, Route, CONFIG_SEED, ROUTE_SEED};
+
+/// 只读报价：按 deposit_from_user 会发送的消息向 OApp 询价，结果通过 return data 返回；
+/// remaining accounts 与 deposit_from_user 相同，原样转发给 quoteSend
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, QuoteDeposit<'info>>, params: DepositParams) -> Result<MessagingFee> {
+    // 按 Token-2022 转账手续费预估金库到账金额；报价只取决于消息长度，receipt_id 用 0 占位即可
+    let fee = transfer_fee(&ctx.accounts.mint.to_account_info(), params.amount, Clock::get()?.epoch)?;
+    let received = params.amount.checked_sub(fee).ok_or(ErrorCode::MathOverflow)?;
+    let outbound = build_deposit_message(&ctx.accounts.mint_config, &ctx.accounts.route, &params, received, 0)?;
+
+    let oapp = OAppAccounts {
+        program: &ctx.accounts.layerzero_oapp_program,
+        peer: &ctx.accounts.peer,
+        store: &ctx.accounts.store,
+        endpoint: &ctx.accounts.endpoint,
+        endpoint_program: &ctx.accounts.endpoint_program,
+        remaining_accounts: ctx.remaining_accounts,
+    };
+    quote_relay_send(&oapp, params.dst_eid, &outbound.message, &outbound.options, params.lz_token_fee.is_some())
+}
+
+#[derive(Accounts)]
+#[instruction(params: DepositParams)]
+pub struct QuoteDeposit<'info> {
+    #[account(seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [CONFIG_SEED, config.key().as_ref(), mint.key().as_ref()],
+        bump = mint_config.bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+
+    #[account(
+        seeds = [ROUTE_SEED, config.key().as_ref(), params.dst_eid.to_be_bytes().as_ref()],
+        bump = route.bump
+    )]
+    pub route: Account<'info, Route>,
+
+    /// CHECK: LayerZero OApp 程序
+    #[account(executable, address = config.layerzero_oapp_program @ ErrorCode::InvalidOAppProgram)]
+    pub layerzero_oapp_program: UncheckedAccount<'info>,
+
+    /// CHECK: LayerZero Peer PDA，地址为路由缓存的 route.peer
+    #[account(address = route.peer @ ErrorCode::InvalidPeer)]
+    pub peer: UncheckedAccount<'info>,
+
+    /// CHECK: LayerZero Store PDA，set_layerzero_programs 时缓存于 config.lz_store
+    #[account(address = config.lz_store @ ErrorCode::InvalidStore)]
+    pub store: UncheckedAccount<'info>,
+
+    /// CHECK: LayerZero Endpoint PDA，set_layerzero_programs 时缓存于 config.lz_endpoint
+    #[account(address = config.lz_endpoint @ ErrorCode::InvalidEndpoint)]
+    pub endpoint: UncheckedAccount<'info>,
+
+    /// CHECK: LayerZero Endpoint 程序
+    #[account(executable, address = config.layerzero_endpoint_program @ ErrorCode::InvalidEndpointProgram)]
+    pub endpoint_program: UncheckedAccount<'info>,
+}
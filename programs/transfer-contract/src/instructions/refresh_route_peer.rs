@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::deposit_from_user::peer_pda;
+use crate::state::{Config, ErrorCode, Route, CONFIG_SEED, ROUTE_SEED};
+
+/// 按 Config 中当前的 OApp 程序重新推导路由缓存的 peer，其余路由参数不变；
+/// set_layerzero_programs 切换 OApp 程序后对每条已有路由调用一次
+pub fn handler(ctx: Context<RefreshRoutePeer>) -> Result<()> {
+    // only admin can update
+    require_keys_eq!(ctx.accounts.admin.key(), ctx.accounts.config.admin, ErrorCode::NotAuthorized);
+
+    let route = &mut ctx.accounts.route;
+    route.peer = peer_pda(&ctx.accounts.config.layerzero_oapp_program, route.dst_eid);
+
+    emit!(RoutePeerUpdated { dst_eid: route.dst_eid, peer: route.peer });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RefreshRoutePeer<'info> {
+    #[account(seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [ROUTE_SEED, config.key().as_ref(), route.dst_eid.to_be_bytes().as_ref()],
+        bump = route.bump
+    )]
+    pub route: Account<'info, Route>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct RoutePeerUpdated {
+    pub dst_eid: u32,
+    pub peer: Pubkey,
+}
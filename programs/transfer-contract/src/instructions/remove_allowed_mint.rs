@@ -0,0 +1,71 @@
+use anchor_lang::error::ErrorCode as AnchorErrorCode;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::state::{Config, ErrorCode, MintConfig, CONFIG_SEED, LP_MINT_SEED, VAULT_SEED};
+
+pub fn handler(ctx: Context<RemoveAllowedMint>) -> Result<()> {
+    // only admin can update
+    require_keys_eq!(ctx.accounts.admin.key(), ctx.accounts.config.admin, ErrorCode::NotAuthorized);
+
+    // 金库里还有余额或仍有 LP 头寸时不允许移除
+    let vault_balance = vault_balance(&ctx.accounts.vault_token_account, &ctx.accounts.token_program.key())?;
+    require!(vault_balance == 0, ErrorCode::MintHasBalance);
+    require!(ctx.accounts.lp_mint.supply == 0, ErrorCode::MintHasBalance);
+
+    emit!(AllowedMintRemoved { mint: ctx.accounts.mint.key() });
+    Ok(())
+}
+
+/// 金库 ATA 从未创建（或已关闭）时余额视为 0
+fn vault_balance(vault_token_account: &AccountInfo, token_program: &Pubkey) -> Result<u64> {
+    if vault_token_account.data_is_empty() {
+        return Ok(0);
+    }
+    require_keys_eq!(*vault_token_account.owner, *token_program, AnchorErrorCode::AccountOwnedByWrongProgram);
+    Ok(TokenAccount::try_deserialize(&mut &vault_token_account.try_borrow_data()?[..])?.amount)
+}
+
+#[derive(Accounts)]
+pub struct RemoveAllowedMint<'info> {
+    #[account(seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    // 关闭 mint 配置，租金退还给 admin
+    #[account(
+        mut,
+        close = admin,
+        seeds = [CONFIG_SEED, config.key().as_ref(), mint.key().as_ref()],
+        bump = mint_config.bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_SEED, config.key().as_ref()],
+        bump = config.vault_authority_bump
+    )]
+    /// CHECK: PDA used only to derive the vault ATA
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// CHECK: 金库 ATA，地址按 vault_authority / mint / token_program 推导；可能不存在
+    #[account(
+        address = get_associated_token_address_with_program_id(&vault_authority.key(), &mint.key(), &token_program.key())
+    )]
+    pub vault_token_account: UncheckedAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(seeds = [LP_MINT_SEED, config.key().as_ref(), mint.key().as_ref()], bump)]
+    pub lp_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct AllowedMintRemoved {
+    pub mint: Pubkey,
+}
@@ -12,7 +12,7 @@ pub fn handler(ctx: Context<SetAllowedCaller>, new_allowed_caller: Pubkey) -> Re
 
 #[derive(Accounts)]
 pub struct SetAllowedCaller<'info> {
-    #[account(mut, seeds = [CONFIG_SEED], bump)]
+    #[account(mut, seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
     pub config: Account<'info, Config>,
 
     #[account(mut)]
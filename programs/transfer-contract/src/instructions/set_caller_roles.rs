@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Config, ErrorCode, CONFIG_SEED};
+
+pub fn handler(ctx: Context<SetCallerRoles>, caller: Pubkey, roles: u8) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    // only admin can update
+    require_keys_eq!(ctx.accounts.admin.key(), config.admin, ErrorCode::NotAuthorized);
+    config.set_caller_roles(caller, roles)?;
+
+    emit!(CallerRolesUpdated { caller, roles });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetCallerRoles<'info> {
+    #[account(mut, seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct CallerRolesUpdated {
+    pub caller: Pubkey,
+    /// 0 表示该调用方已被移除
+    pub roles: u8,
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Config, ErrorCode, CONFIG_SEED};
+
+pub fn handler(ctx: Context<SetGuardian>, guardian: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    // only admin can update
+    require_keys_eq!(ctx.accounts.admin.key(), config.admin, ErrorCode::NotAuthorized);
+    config.guardian = guardian;
+
+    emit!(GuardianUpdated { guardian });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    #[account(mut, seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct GuardianUpdated {
+    /// Pubkey::default() 表示移除 guardian
+    pub guardian: Pubkey,
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Config, ErrorCode, CONFIG_SEED};
+
+/// 切换 LayerZero 程序（devnet / mainnet / 本地 mock-oapp），同一份程序二进制即可对接不同环境。
+/// 路由缓存的 peer 按旧 OApp 程序推导，切换后需对每条路由调用 refresh_route_peer（或重新 set_route）
+pub fn handler(ctx: Context<SetLayerzeroPrograms>, oapp_program: Pubkey, endpoint_program: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    // only admin can update
+    require_keys_eq!(ctx.accounts.admin.key(), config.admin, ErrorCode::NotAuthorized);
+    require_keys_neq!(oapp_program, Pubkey::default(), ErrorCode::InvalidOAppProgram);
+    require_keys_neq!(endpoint_program, Pubkey::default(), ErrorCode::InvalidEndpointProgram);
+    config.set_layerzero_programs(oapp_program, endpoint_program);
+
+    emit!(LayerZeroProgramsUpdated { oapp_program, endpoint_program });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetLayerzeroPrograms<'info> {
+    #[account(mut, seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct LayerZeroProgramsUpdated {
+    pub oapp_program: Pubkey,
+    pub endpoint_program: Pubkey,
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::state::mint_config::MAX_TOTAL_FEE_BPS;
+use crate::state::{Config, ErrorCode, CONFIG_SEED};
+
+pub fn handler(ctx: Context<SetRefundPolicy>, timeout_secs: i64, fee_bps: u16) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    // only admin can update
+    require_keys_eq!(ctx.accounts.admin.key(), config.admin, ErrorCode::NotAuthorized);
+    require!(timeout_secs >= 0, ErrorCode::InvalidRefundPolicy);
+    require!(fee_bps <= MAX_TOTAL_FEE_BPS, ErrorCode::FeeTooHigh);
+    config.refund_timeout_secs = timeout_secs;
+    config.refund_fee_bps = fee_bps;
+
+    emit!(RefundPolicyUpdated { timeout_secs, fee_bps });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRefundPolicy<'info> {
+    #[account(mut, seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct RefundPolicyUpdated {
+    pub timeout_secs: i64,
+    pub fee_bps: u16,
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Config, ErrorCode, CONFIG_SEED};
+
+pub fn handler(ctx: Context<SetRemotePeer>, src_eid: u32, sender: [u8; 32]) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    // only admin can update
+    require_keys_eq!(ctx.accounts.admin.key(), config.admin, ErrorCode::NotAuthorized);
+    config.set_remote_peer(src_eid, sender)?;
+
+    emit!(RemotePeerUpdated { src_eid, sender });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRemotePeer<'info> {
+    #[account(mut, seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct RemotePeerUpdated {
+    pub src_eid: u32,
+    /// 全 0 表示该源链的 peer 已被移除
+    pub sender: [u8; 32],
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::deposit_from_user::peer_pda;
+use crate::state::{Config, ErrorCode, ExecutorConfig, Route, RouteParams, RouteToken, CONFIG_SEED, ROUTE_SEED};
+
+pub fn handler(ctx: Context<SetRoute>, dst_eid: u32, params: RouteParams) -> Result<()> {
+    // only admin can update
+    require_keys_eq!(ctx.accounts.admin.key(), ctx.accounts.config.admin, ErrorCode::NotAuthorized);
+
+    let route = &mut ctx.accounts.route;
+    route.dst_eid = dst_eid;
+    route.bump = ctx.bumps.route;
+    // 入金时据此校验客户端传入的 peer；切换 OApp 程序后由 refresh_route_peer 重新推导
+    route.peer = peer_pda(&ctx.accounts.config.layerzero_oapp_program, dst_eid);
+    route.apply(params)?;
+
+    emit!(RouteUpdated {
+        dst_eid,
+        enabled: route.enabled,
+        min_amount: route.min_amount,
+        max_amount: route.max_amount,
+        executor: route.executor,
+        tokens: route.tokens.clone(),
+        merchants: route.merchants.clone(),
+        peer: route.peer,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(dst_eid: u32)]
+pub struct SetRoute<'info> {
+    #[account(seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    // 首次设置时创建，之后覆盖；禁用路由用 enabled = false
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = Route::SPACE,
+        seeds = [ROUTE_SEED, config.key().as_ref(), dst_eid.to_be_bytes().as_ref()],
+        bump
+    )]
+    pub route: Account<'info, Route>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct RouteUpdated {
+    pub dst_eid: u32,
+    pub enabled: bool,
+    pub min_amount: u64,
+    pub max_amount: u64,
+    pub executor: ExecutorConfig,
+    pub tokens: Vec<RouteToken>,
+    pub merchants: Vec<[u8; 32]>,
+    pub peer: Pubkey,
+}
@@ -1,16 +1,21 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token as token;
-use anchor_spl::token::{Mint, TokenAccount, Token, TransferChecked};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
 
-use crate::state::{Config, ErrorCode, CONFIG_SEED, VAULT_SEED};
+use crate::state::{Config, ErrorCode, MintConfig, CONFIG_SEED, VAULT_SEED, ROLE_PAYOUT, PAUSE_PAYOUTS};
 
 pub fn handler(ctx: Context<TransferOut>, amount: u64) -> Result<()> {
     let config = &ctx.accounts.config;
 
-    require!(config.is_allowed_mint(&ctx.accounts.mint.key()), ErrorCode::MintNotAllowed);
+    // authority 必须是 admin / allowed_caller_authority，或拥有 ROLE_PAYOUT 的调用方
+    let caller_roles = config.authorize(&ctx.accounts.authority.key(), ROLE_PAYOUT)?;
+
+    config.require_not_paused(&ctx.accounts.mint_config, PAUSE_PAYOUTS)?;
+    require!(ctx.accounts.mint_config.enabled, ErrorCode::MintDisabled);
     require_keys_eq!(ctx.accounts.recipient_token_account.mint, ctx.accounts.mint.key(), ErrorCode::RecipientMintMismatch);
     require_keys_eq!(ctx.accounts.vault_token_account.mint, ctx.accounts.mint.key(), ErrorCode::VaultMintMismatch);
 
+    ctx.accounts.mint_config.record_outflow(amount, Clock::get()?.unix_timestamp)?;
+
     let cpi_accounts = TransferChecked {
         from: ctx.accounts.vault_token_account.to_account_info(),
         to: ctx.accounts.recipient_token_account.to_account_info(),
@@ -22,16 +27,26 @@ pub fn handler(ctx: Context<TransferOut>, amount: u64) -> Result<()> {
     let bump = config.vault_authority_bump;
     let config_key = ctx.accounts.config.key();
     let seeds: &[&[u8]] = &[VAULT_SEED, config_key.as_ref(), &[bump]];
-    token::transfer_checked(
+    token_interface::transfer_checked(
         CpiContext::new_with_signer(cpi_program, cpi_accounts, &[seeds]),
         amount,
         ctx.accounts.mint.decimals,
-    )
+    )?;
+
+    emit!(TransferOutEvent {
+        authority: ctx.accounts.authority.key(),
+        caller_roles,
+        mint: ctx.accounts.mint.key(),
+        recipient_token_account: ctx.accounts.recipient_token_account.key(),
+        amount,
+    });
+
+    Ok(())
 }
 
 #[derive(Accounts)]
 pub struct TransferOut<'info> {
-    #[account(seeds = [CONFIG_SEED], bump)]
+    #[account(seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
     pub config: Account<'info, Config>,
 
     #[account(mut)]
@@ -44,14 +59,33 @@ pub struct TransferOut<'info> {
     /// CHECK: PDA used only as signing authority
     pub vault_authority: UncheckedAccount<'info>,
 
-    #[account(mut)]
-    pub vault_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(mut)]
-    pub recipient_token_account: Account<'info, TokenAccount>,
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    pub mint: Account<'info, Mint>,
-    pub token_program: Program<'info, Token>,
-}
+    pub mint: InterfaceAccount<'info, Mint>,
 
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED, config.key().as_ref(), mint.key().as_ref()],
+        bump = mint_config.bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
 
+#[event]
+pub struct TransferOutEvent {
+    pub authority: Pubkey,
+    pub caller_roles: u8,
+    pub mint: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::pause::PauseUpdated;
+use crate::state::pause::validate_pause_flags;
+use crate::state::{Config, ErrorCode, CONFIG_SEED};
+
+pub fn handler(ctx: Context<Unpause>, flags: u8) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    // only admin can unpause
+    require_keys_eq!(ctx.accounts.admin.key(), config.admin, ErrorCode::NotAuthorized);
+    validate_pause_flags(flags)?;
+    config.paused &= !flags;
+
+    emit!(PauseUpdated {
+        mint: None,
+        authority: ctx.accounts.admin.key(),
+        paused: config.paused,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Unpause<'info> {
+    #[account(mut, seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::instructions::pause::PauseUpdated;
+use crate::state::pause::validate_pause_flags;
+use crate::state::{Config, ErrorCode, MintConfig, CONFIG_SEED};
+
+pub fn handler(ctx: Context<UnpauseMint>, flags: u8) -> Result<()> {
+    // only admin can unpause
+    require_keys_eq!(ctx.accounts.admin.key(), ctx.accounts.config.admin, ErrorCode::NotAuthorized);
+    validate_pause_flags(flags)?;
+    let mint_config = &mut ctx.accounts.mint_config;
+    mint_config.paused &= !flags;
+
+    emit!(PauseUpdated {
+        mint: Some(mint_config.mint),
+        authority: ctx.accounts.admin.key(),
+        paused: mint_config.paused,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnpauseMint<'info> {
+    #[account(seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED, config.key().as_ref(), mint.key().as_ref()],
+        bump = mint_config.bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+
+    pub admin: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::{Config, DstToken, ErrorCode, MintConfig, MintConfigParams, CONFIG_SEED};
+
+pub fn handler(ctx: Context<UpdateMintConfig>, params: MintConfigParams) -> Result<()> {
+    // only admin can update
+    require_keys_eq!(ctx.accounts.admin.key(), ctx.accounts.config.admin, ErrorCode::NotAuthorized);
+
+    let mint_config = &mut ctx.accounts.mint_config;
+    mint_config.apply(params)?;
+
+    emit!(MintConfigUpdated {
+        mint: mint_config.mint,
+        enabled: mint_config.enabled,
+        min_deposit: mint_config.min_deposit,
+        max_deposit: mint_config.max_deposit,
+        daily_outflow_cap: mint_config.daily_outflow_cap,
+        dst_tokens: mint_config.dst_tokens.clone(),
+        protocol_fee_bps: mint_config.protocol_fee_bps,
+        lp_fee_bps: mint_config.lp_fee_bps,
+        refund_dust: mint_config.refund_dust,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateMintConfig<'info> {
+    #[account(seeds = [CONFIG_SEED, config.vault_id.to_be_bytes().as_ref()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED, config.key().as_ref(), mint.key().as_ref()],
+        bump = mint_config.bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+
+    pub admin: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+#[event]
+pub struct MintConfigUpdated {
+    pub mint: Pubkey,
+    pub enabled: bool,
+    pub min_deposit: u64,
+    pub max_deposit: u64,
+    pub daily_outflow_cap: u64,
+    pub dst_tokens: Vec<DstToken>,
+    pub protocol_fee_bps: u16,
+    pub lp_fee_bps: u16,
+    pub refund_dust: bool,
+}
@@ -4,6 +4,8 @@ declare_id!("GSPmsxkxd5qR5HG4fhUd5cBrVkWNJWi6pWUFQnYmTEc1");
 
 pub mod state;
 pub mod instructions;
+pub mod messaging;
+pub mod options;
 
 // Anchor 宏期望在 crate 根找到每个指令对应的 __client_accounts_* 模块
 #[allow(non_snake_case)]
@@ -18,6 +20,52 @@ pub mod __client_accounts_lp_deposit { pub use crate::instructions::lp_deposit::
 pub mod __client_accounts_lp_withdraw { pub use crate::instructions::lp_withdraw::__client_accounts_lp_withdraw::*; }
 #[allow(non_snake_case)]
 pub mod __client_accounts_set_allowed_caller { pub use crate::instructions::set_allowed_caller::__client_accounts_set_allowed_caller::*; }
+#[allow(non_snake_case)]
+pub mod __client_accounts_set_caller_roles { pub use crate::instructions::set_caller_roles::__client_accounts_set_caller_roles::*; }
+#[allow(non_snake_case)]
+pub mod __client_accounts_add_allowed_mint { pub use crate::instructions::add_allowed_mint::__client_accounts_add_allowed_mint::*; }
+#[allow(non_snake_case)]
+pub mod __client_accounts_remove_allowed_mint { pub use crate::instructions::remove_allowed_mint::__client_accounts_remove_allowed_mint::*; }
+#[allow(non_snake_case)]
+pub mod __client_accounts_update_mint_config { pub use crate::instructions::update_mint_config::__client_accounts_update_mint_config::*; }
+#[allow(non_snake_case)]
+pub mod __client_accounts_propose_admin { pub use crate::instructions::propose_admin::__client_accounts_propose_admin::*; }
+#[allow(non_snake_case)]
+pub mod __client_accounts_accept_admin { pub use crate::instructions::accept_admin::__client_accounts_accept_admin::*; }
+#[allow(non_snake_case)]
+pub mod __client_accounts_cancel_admin_proposal { pub use crate::instructions::cancel_admin_proposal::__client_accounts_cancel_admin_proposal::*; }
+#[allow(non_snake_case)]
+pub mod __client_accounts_set_guardian { pub use crate::instructions::set_guardian::__client_accounts_set_guardian::*; }
+#[allow(non_snake_case)]
+pub mod __client_accounts_pause { pub use crate::instructions::pause::__client_accounts_pause::*; }
+#[allow(non_snake_case)]
+pub mod __client_accounts_unpause { pub use crate::instructions::unpause::__client_accounts_unpause::*; }
+#[allow(non_snake_case)]
+pub mod __client_accounts_pause_mint { pub use crate::instructions::pause_mint::__client_accounts_pause_mint::*; }
+#[allow(non_snake_case)]
+pub mod __client_accounts_unpause_mint { pub use crate::instructions::unpause_mint::__client_accounts_unpause_mint::*; }
+#[allow(non_snake_case)]
+pub mod __client_accounts_set_treasury { pub use crate::instructions::set_treasury::__client_accounts_set_treasury::*; }
+#[allow(non_snake_case)]
+pub mod __client_accounts_set_remote_peer { pub use crate::instructions::set_remote_peer::__client_accounts_set_remote_peer::*; }
+#[allow(non_snake_case)]
+pub mod __client_accounts_lz_receive { pub use crate::instructions::lz_receive::__client_accounts_lz_receive::*; }
+#[allow(non_snake_case)]
+pub mod __client_accounts_quote_deposit { pub use crate::instructions::quote_deposit::__client_accounts_quote_deposit::*; }
+#[allow(non_snake_case)]
+pub mod __client_accounts_set_route { pub use crate::instructions::set_route::__client_accounts_set_route::*; }
+#[allow(non_snake_case)]
+pub mod __client_accounts_set_refund_policy { pub use crate::instructions::set_refund_policy::__client_accounts_set_refund_policy::*; }
+#[allow(non_snake_case)]
+pub mod __client_accounts_mark_payment_failed { pub use crate::instructions::mark_payment_failed::__client_accounts_mark_payment_failed::*; }
+#[allow(non_snake_case)]
+pub mod __client_accounts_lz_receive_ack { pub use crate::instructions::lz_receive_ack::__client_accounts_lz_receive_ack::*; }
+#[allow(non_snake_case)]
+pub mod __client_accounts_claim_refund { pub use crate::instructions::claim_refund::__client_accounts_claim_refund::*; }
+#[allow(non_snake_case)]
+pub mod __client_accounts_set_layerzero_programs { pub use crate::instructions::set_layerzero_programs::__client_accounts_set_layerzero_programs::*; }
+#[allow(non_snake_case)]
+pub mod __client_accounts_refresh_route_peer { pub use crate::instructions::refresh_route_peer::__client_accounts_refresh_route_peer::*; }
 
 // (已在上方以 pub mod __client_accounts_* 定义，无需再重导入)
 
@@ -28,6 +76,29 @@ pub use instructions::transfer_out::TransferOut as TransferOut;
 pub use instructions::lp_deposit::LpDeposit as LpDeposit;
 pub use instructions::lp_withdraw::LpWithdraw as LpWithdraw;
 pub use instructions::set_allowed_caller::SetAllowedCaller as SetAllowedCaller;
+pub use instructions::set_caller_roles::SetCallerRoles as SetCallerRoles;
+pub use instructions::add_allowed_mint::AddAllowedMint as AddAllowedMint;
+pub use instructions::remove_allowed_mint::RemoveAllowedMint as RemoveAllowedMint;
+pub use instructions::update_mint_config::UpdateMintConfig as UpdateMintConfig;
+pub use instructions::propose_admin::ProposeAdmin as ProposeAdmin;
+pub use instructions::accept_admin::AcceptAdmin as AcceptAdmin;
+pub use instructions::cancel_admin_proposal::CancelAdminProposal as CancelAdminProposal;
+pub use instructions::set_guardian::SetGuardian as SetGuardian;
+pub use instructions::pause::Pause as Pause;
+pub use instructions::unpause::Unpause as Unpause;
+pub use instructions::pause_mint::PauseMint as PauseMint;
+pub use instructions::unpause_mint::UnpauseMint as UnpauseMint;
+pub use instructions::set_treasury::SetTreasury as SetTreasury;
+pub use instructions::set_remote_peer::SetRemotePeer as SetRemotePeer;
+pub use instructions::lz_receive::LzReceive as LzReceive;
+pub use instructions::quote_deposit::QuoteDeposit as QuoteDeposit;
+pub use instructions::set_route::SetRoute as SetRoute;
+pub use instructions::set_refund_policy::SetRefundPolicy as SetRefundPolicy;
+pub use instructions::mark_payment_failed::MarkPaymentFailed as MarkPaymentFailed;
+pub use instructions::lz_receive_ack::LzReceiveAck as LzReceiveAck;
+pub use instructions::claim_refund::ClaimRefund as ClaimRefund;
+pub use instructions::set_layerzero_programs::SetLayerzeroPrograms as SetLayerzeroPrograms;
+pub use instructions::refresh_route_peer::RefreshRoutePeer as RefreshRoutePeer;
 
 #[program]
 pub mod transfer_contract {
@@ -35,10 +106,10 @@ pub mod transfer_contract {
 
     pub fn initialize(
         ctx: Context<Initialize>,
+        vault_id: u64,
         allowed_caller_authority: Pubkey,
-        allowed_mints: Vec<Pubkey>,
     ) -> Result<()> {
-        instructions::initialize::handler(ctx, allowed_caller_authority, allowed_mints)
+        instructions::initialize::handler(ctx, vault_id, allowed_caller_authority)
     }
 
     pub fn deposit_from_user<'info>(
@@ -64,9 +135,9 @@ pub mod transfer_contract {
 
     pub fn lp_withdraw(
         ctx: Context<LpWithdraw>,
-        amount: u64,
+        shares: u64,
     ) -> Result<()> {
-        instructions::lp_withdraw::handler(ctx, amount)
+        instructions::lp_withdraw::handler(ctx, shares)
     }
 
     pub fn set_allowed_caller(
@@ -75,5 +146,152 @@ pub mod transfer_contract {
     ) -> Result<()> {
         instructions::set_allowed_caller::handler(ctx, new_allowed_caller)
     }
-}
 
+    pub fn set_caller_roles(
+        ctx: Context<SetCallerRoles>,
+        caller: Pubkey,
+        roles: u8,
+    ) -> Result<()> {
+        instructions::set_caller_roles::handler(ctx, caller, roles)
+    }
+
+    pub fn add_allowed_mint(
+        ctx: Context<AddAllowedMint>,
+        params: state::MintConfigParams,
+    ) -> Result<()> {
+        instructions::add_allowed_mint::handler(ctx, params)
+    }
+
+    pub fn remove_allowed_mint(ctx: Context<RemoveAllowedMint>) -> Result<()> {
+        instructions::remove_allowed_mint::handler(ctx)
+    }
+
+    pub fn update_mint_config(
+        ctx: Context<UpdateMintConfig>,
+        params: state::MintConfigParams,
+    ) -> Result<()> {
+        instructions::update_mint_config::handler(ctx, params)
+    }
+
+    pub fn propose_admin(
+        ctx: Context<ProposeAdmin>,
+        new_admin: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_admin::handler(ctx, new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin::handler(ctx)
+    }
+
+    pub fn cancel_admin_proposal(ctx: Context<CancelAdminProposal>) -> Result<()> {
+        instructions::cancel_admin_proposal::handler(ctx)
+    }
+
+    pub fn set_guardian(
+        ctx: Context<SetGuardian>,
+        guardian: Pubkey,
+    ) -> Result<()> {
+        instructions::set_guardian::handler(ctx, guardian)
+    }
+
+    pub fn pause(
+        ctx: Context<Pause>,
+        flags: u8,
+    ) -> Result<()> {
+        instructions::pause::handler(ctx, flags)
+    }
+
+    pub fn unpause(
+        ctx: Context<Unpause>,
+        flags: u8,
+    ) -> Result<()> {
+        instructions::unpause::handler(ctx, flags)
+    }
+
+    pub fn pause_mint(
+        ctx: Context<PauseMint>,
+        flags: u8,
+    ) -> Result<()> {
+        instructions::pause_mint::handler(ctx, flags)
+    }
+
+    pub fn unpause_mint(
+        ctx: Context<UnpauseMint>,
+        flags: u8,
+    ) -> Result<()> {
+        instructions::unpause_mint::handler(ctx, flags)
+    }
+
+    pub fn set_treasury(
+        ctx: Context<SetTreasury>,
+        treasury: Pubkey,
+    ) -> Result<()> {
+        instructions::set_treasury::handler(ctx, treasury)
+    }
+
+    pub fn set_remote_peer(
+        ctx: Context<SetRemotePeer>,
+        src_eid: u32,
+        sender: [u8; 32],
+    ) -> Result<()> {
+        instructions::set_remote_peer::handler(ctx, src_eid, sender)
+    }
+
+    pub fn lz_receive(
+        ctx: Context<LzReceive>,
+        params: instructions::lz_receive::LzReceiveParams,
+    ) -> Result<()> {
+        instructions::lz_receive::handler(ctx, params)
+    }
+
+    pub fn quote_deposit<'info>(
+        ctx: Context<'_, '_, '_, 'info, QuoteDeposit<'info>>,
+        params: instructions::deposit_from_user::DepositParams,
+    ) -> Result<instructions::deposit_from_user::MessagingFee> {
+        instructions::quote_deposit::handler(ctx, params)
+    }
+
+    pub fn set_route(
+        ctx: Context<SetRoute>,
+        dst_eid: u32,
+        params: state::RouteParams,
+    ) -> Result<()> {
+        instructions::set_route::handler(ctx, dst_eid, params)
+    }
+
+    pub fn set_refund_policy(
+        ctx: Context<SetRefundPolicy>,
+        timeout_secs: i64,
+        fee_bps: u16,
+    ) -> Result<()> {
+        instructions::set_refund_policy::handler(ctx, timeout_secs, fee_bps)
+    }
+
+    pub fn mark_payment_failed(ctx: Context<MarkPaymentFailed>) -> Result<()> {
+        instructions::mark_payment_failed::handler(ctx)
+    }
+
+    pub fn lz_receive_ack(
+        ctx: Context<LzReceiveAck>,
+        params: instructions::lz_receive::LzReceiveParams,
+    ) -> Result<()> {
+        instructions::lz_receive_ack::handler(ctx, params)
+    }
+
+    pub fn claim_refund(ctx: Context<ClaimRefund>) -> Result<()> {
+        instructions::claim_refund::handler(ctx)
+    }
+
+    pub fn set_layerzero_programs(
+        ctx: Context<SetLayerzeroPrograms>,
+        oapp_program: Pubkey,
+        endpoint_program: Pubkey,
+    ) -> Result<()> {
+        instructions::set_layerzero_programs::handler(ctx, oapp_program, endpoint_program)
+    }
+
+    pub fn refresh_route_peer(ctx: Context<RefreshRoutePeer>) -> Result<()> {
+        instructions::refresh_route_peer::handler(ctx)
+    }
+}
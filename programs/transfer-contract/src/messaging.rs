@@ -0,0 +1,180 @@
+//! LayerZero 跨链消息编解码。
+//!
+//! 所有消息都按 EVM ABI 编码（每个字段 32 字节）：
+//! `abi.encode(uint8 version, uint8 tag, ...fields)`，EVM 合约可以直接 `abi.decode`。
+
+use anchor_lang::prelude::*;
+
+use crate::state::ErrorCode;
+
+pub const MESSAGE_VERSION: u8 = 1;
+
+/// Solana → EVM：商户在目标链收款；EVM → Solana：金库向收款人打款
+pub const TAG_PAYOUT: u8 = 101;
+/// 退款请求
+pub const TAG_REFUND: u8 = 102;
+/// 目标链对一笔付款的确认（成功或失败）
+pub const TAG_ACK: u8 = 103;
+/// 管理配置同步
+pub const TAG_ADMIN_SYNC: u8 = 104;
+
+const WORD: usize = 32;
+
+/// 出站时 token / recipient 是 EVM 地址（左边补 12 个 0），入站时是 Solana 公钥
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Payout {
+    /// 出站为本程序的 PaymentReceipt 编号，入站为源链侧的付款编号
+    pub receipt_id: u64,
+    pub token: [u8; 32],
+    pub recipient: [u8; 32],
+    /// 按接收方代币的 decimals 计价；uint256 中超出 u128 的值不支持
+    pub amount: u128,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Refund {
+    pub receipt_id: u64,
+    pub amount: u64,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Ack {
+    pub receipt_id: u64,
+    pub success: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AdminSync {
+    pub mint: [u8; 32],
+    pub enabled: bool,
+    pub paused: u8,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Message {
+    Payout(Payout),
+    Refund(Refund),
+    Ack(Ack),
+    AdminSync(AdminSync),
+}
+
+impl Message {
+    pub fn tag(&self) -> u8 {
+        match self {
+            Message::Payout(_) => TAG_PAYOUT,
+            Message::Refund(_) => TAG_REFUND,
+            Message::Ack(_) => TAG_ACK,
+            Message::AdminSync(_) => TAG_ADMIN_SYNC,
+        }
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(6 * WORD);
+        out.extend_from_slice(&uint_word(MESSAGE_VERSION as u128));
+        out.extend_from_slice(&uint_word(self.tag() as u128));
+        match self {
+            Message::Payout(p) => {
+                out.extend_from_slice(&uint_word(p.receipt_id as u128));
+                out.extend_from_slice(&p.token);
+                out.extend_from_slice(&p.recipient);
+                out.extend_from_slice(&uint_word(p.amount));
+            }
+            Message::Refund(r) => {
+                out.extend_from_slice(&uint_word(r.receipt_id as u128));
+                out.extend_from_slice(&uint_word(r.amount as u128));
+            }
+            Message::Ack(a) => {
+                out.extend_from_slice(&uint_word(a.receipt_id as u128));
+                out.extend_from_slice(&uint_word(a.success as u128));
+            }
+            Message::AdminSync(s) => {
+                out.extend_from_slice(&s.mint);
+                out.extend_from_slice(&uint_word(s.enabled as u128));
+                out.extend_from_slice(&uint_word(s.paused as u128));
+            }
+        }
+        out
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        require!(bytes.len() >= 2 * WORD && bytes.len() % WORD == 0, ErrorCode::InvalidPayload);
+        let words: Vec<&[u8; 32]> = bytes.chunks_exact(WORD).map(|w| w.try_into().unwrap()).collect();
+
+        require!(decode_uint(words[0], u8::MAX as u128)? == MESSAGE_VERSION as u128, ErrorCode::UnsupportedMessageVersion);
+        let tag = decode_uint(words[1], u8::MAX as u128)? as u8;
+        let fields = &words[2..];
+        let expect = |n: usize| -> Result<()> {
+            require!(fields.len() == n, ErrorCode::InvalidPayload);
+            Ok(())
+        };
+
+        let message = match tag {
+            TAG_PAYOUT => {
+                expect(4)?;
+                Message::Payout(Payout {
+                    receipt_id: decode_uint(fields[0], u64::MAX as u128)? as u64,
+                    token: *fields[1],
+                    recipient: *fields[2],
+                    amount: decode_uint(fields[3], u128::MAX)?,
+                })
+            }
+            TAG_REFUND => {
+                expect(2)?;
+                Message::Refund(Refund {
+                    receipt_id: decode_uint(fields[0], u64::MAX as u128)? as u64,
+                    amount: decode_uint(fields[1], u64::MAX as u128)? as u64,
+                })
+            }
+            TAG_ACK => {
+                expect(2)?;
+                Message::Ack(Ack {
+                    receipt_id: decode_uint(fields[0], u64::MAX as u128)? as u64,
+                    success: decode_uint(fields[1], 1)? == 1,
+                })
+            }
+            TAG_ADMIN_SYNC => {
+                expect(3)?;
+                Message::AdminSync(AdminSync {
+                    mint: *fields[0],
+                    enabled: decode_uint(fields[1], 1)? == 1,
+                    paused: decode_uint(fields[2], u8::MAX as u128)? as u8,
+                })
+            }
+            _ => return err!(ErrorCode::InvalidPayload),
+        };
+        Ok(message)
+    }
+}
+
+/// 32 字节 word → 20 字节 EVM 地址；高 12 字节必须为 0
+pub fn evm_address(word: &[u8; 32]) -> Result<[u8; 20]> {
+    require!(word[..12].iter().all(|b| *b == 0), ErrorCode::InvalidEvmAddress);
+    Ok(word[12..].try_into().unwrap())
+}
+
+/// 20 字节 EVM 地址 → 左边补 12 个 0 的 32 字节 word
+pub fn evm_word(address: &[u8; 20]) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[12..].copy_from_slice(address);
+    word
+}
+
+/// OApp 的 relay_send / quote 以 "0x" 开头的 hex 字符串接收消息
+pub fn to_hex(message: &[u8]) -> String {
+    format!("0x{}", hex::encode(message))
+}
+
+/// uintN：大端序，左边补 0
+fn uint_word(value: u128) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[16..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+/// 严格解码：超出 max 的值（包括高位非 0）视为格式错误
+fn decode_uint(word: &[u8; 32], max: u128) -> Result<u128> {
+    require!(word[..16].iter().all(|b| *b == 0), ErrorCode::InvalidPayload);
+    let value = u128::from_be_bytes(word[16..].try_into().unwrap());
+    require!(value <= max, ErrorCode::InvalidPayload);
+    Ok(value)
+}
@@ -0,0 +1,153 @@
+//! LayerZero 执行选项（type-3 options）编码与校验。
+//!
+//! 布局与 EVM 侧 `OptionsBuilder` 一致（整数均为大端）：
+//! `uint16 type(3)`，随后每个 option 为 `uint8 worker_id | uint16 size | body`：
+//! - executor（worker 1）：body = `uint8 option_type | params`；
+//! - DVN（worker 2）：body = `uint8 dvn_idx | uint8 option_type | params`。
+
+use anchor_lang::prelude::*;
+
+use crate::state::ErrorCode;
+
+pub const OPTIONS_TYPE_3: u16 = 3;
+
+pub const EXECUTOR_WORKER_ID: u8 = 1;
+pub const DVN_WORKER_ID: u8 = 2;
+
+pub const OPTION_TYPE_LZRECEIVE: u8 = 1;
+pub const OPTION_TYPE_NATIVE_DROP: u8 = 2;
+pub const OPTION_TYPE_LZCOMPOSE: u8 = 3;
+pub const OPTION_TYPE_ORDERED_EXECUTION: u8 = 4;
+
+/// 客户端传入的 options 长度上限
+pub const MAX_OPTIONS_LEN: usize = 256;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ExecutorOption {
+    /// 目标链执行 lzReceive 的 gas 与附带的原生代币
+    LzReceive { gas: u128, value: u128 },
+    /// 向 receiver 空投目标链原生代币
+    NativeDrop { amount: u128, receiver: [u8; 32] },
+    LzCompose { index: u16, gas: u128, value: u128 },
+    OrderedExecution,
+}
+
+/// 与 EVM `OptionsBuilder.newOptions().addExecutor...Option(..)` 产出相同的字节
+#[derive(Clone, Debug)]
+pub struct OptionsBuilder {
+    bytes: Vec<u8>,
+}
+
+impl Default for OptionsBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OptionsBuilder {
+    pub fn new() -> Self {
+        Self { bytes: OPTIONS_TYPE_3.to_be_bytes().to_vec() }
+    }
+
+    /// value 为 0 时只编码 gas
+    pub fn lz_receive(self, gas: u128, value: u128) -> Self {
+        let mut params = gas.to_be_bytes().to_vec();
+        if value > 0 {
+            params.extend_from_slice(&value.to_be_bytes());
+        }
+        self.executor_option(OPTION_TYPE_LZRECEIVE, &params)
+    }
+
+    pub fn native_drop(self, amount: u128, receiver: [u8; 32]) -> Self {
+        let mut params = amount.to_be_bytes().to_vec();
+        params.extend_from_slice(&receiver);
+        self.executor_option(OPTION_TYPE_NATIVE_DROP, &params)
+    }
+
+    /// value 为 0 时只编码 index 与 gas
+    pub fn lz_compose(self, index: u16, gas: u128, value: u128) -> Self {
+        let mut params = index.to_be_bytes().to_vec();
+        params.extend_from_slice(&gas.to_be_bytes());
+        if value > 0 {
+            params.extend_from_slice(&value.to_be_bytes());
+        }
+        self.executor_option(OPTION_TYPE_LZCOMPOSE, &params)
+    }
+
+    pub fn ordered_execution(self) -> Self {
+        self.executor_option(OPTION_TYPE_ORDERED_EXECUTION, &[])
+    }
+
+    pub fn build(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    fn executor_option(mut self, option_type: u8, params: &[u8]) -> Self {
+        self.bytes.push(EXECUTOR_WORKER_ID);
+        self.bytes.extend_from_slice(&(params.len() as u16 + 1).to_be_bytes());
+        self.bytes.push(option_type);
+        self.bytes.extend_from_slice(params);
+        self
+    }
+}
+
+/// 解析 type-3 options 中的 executor 选项；DVN 选项只校验结构后跳过
+pub fn decode_executor_options(options: &[u8]) -> Result<Vec<ExecutorOption>> {
+    let mut rest = options.strip_prefix(&OPTIONS_TYPE_3.to_be_bytes()).ok_or(ErrorCode::InvalidOptions)?;
+    let mut decoded = Vec::new();
+    while let Some((&worker_id, tail)) = rest.split_first() {
+        let size = tail.get(..2).ok_or(ErrorCode::InvalidOptions)?;
+        let size = u16::from_be_bytes([size[0], size[1]]) as usize;
+        let body = tail.get(2..2 + size).ok_or(ErrorCode::InvalidOptions)?;
+        rest = &tail[2 + size..];
+        match worker_id {
+            EXECUTOR_WORKER_ID => {
+                let (&option_type, params) = body.split_first().ok_or(ErrorCode::InvalidOptions)?;
+                decoded.push(decode_executor_option(option_type, params)?);
+            }
+            // dvn_idx 与 option_type
+            DVN_WORKER_ID => require!(body.len() >= 2, ErrorCode::InvalidOptions),
+            _ => return err!(ErrorCode::InvalidOptions),
+        }
+    }
+    Ok(decoded)
+}
+
+fn decode_executor_option(option_type: u8, params: &[u8]) -> Result<ExecutorOption> {
+    let option = match (option_type, params.len()) {
+        (OPTION_TYPE_LZRECEIVE, 16 | 32) => {
+            ExecutorOption::LzReceive { gas: u128_at(params, 0), value: optional_u128_at(params, 16) }
+        }
+        (OPTION_TYPE_NATIVE_DROP, 48) => ExecutorOption::NativeDrop {
+            amount: u128_at(params, 0),
+            receiver: params[16..48].try_into().unwrap(),
+        },
+        (OPTION_TYPE_LZCOMPOSE, 18 | 34) => ExecutorOption::LzCompose {
+            index: u16::from_be_bytes([params[0], params[1]]),
+            gas: u128_at(params, 2),
+            value: optional_u128_at(params, 18),
+        },
+        (OPTION_TYPE_ORDERED_EXECUTION, 0) => ExecutorOption::OrderedExecution,
+        _ => return err!(ErrorCode::InvalidOptions),
+    };
+    Ok(option)
+}
+
+fn u128_at(bytes: &[u8], offset: usize) -> u128 {
+    u128::from_be_bytes(bytes[offset..offset + 16].try_into().unwrap())
+}
+
+fn optional_u128_at(bytes: &[u8], offset: usize) -> u128 {
+    if bytes.len() > offset { u128_at(bytes, offset) } else { 0 }
+}
+
+/// 客户端传入的 options：必须是合法的 type-3 options，且为 lzReceive 设置了 gas，否则消息到达目标链后无人执行
+pub fn validate_options(options: &[u8]) -> Result<()> {
+    require!(options.len() <= MAX_OPTIONS_LEN, ErrorCode::InvalidOptions);
+    let decoded = decode_executor_options(options)?;
+    require!(
+        decoded.iter().any(|o| matches!(o, ExecutorOption::LzReceive { gas, .. } if *gas > 0)),
+        ErrorCode::MissingLzReceiveGas
+    );
+    Ok(())
+}
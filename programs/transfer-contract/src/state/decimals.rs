@@ -0,0 +1,15 @@
+//! 跨链金额的 decimals 换算。源链金额为 SPL mint 的 u64，
+//! 目标链代币可能有不同的 decimals（例如 6 位 USDC → 18 位代币），换算在 u128 上进行。
+
+/// 把 amount 从 src_decimals 换算到 dst_decimals，返回 (换算后金额, 无法表示的尾差 dust)。
+/// 放大时 dust 恒为 0；缩小时 dust 为被截掉的源链单位。
+pub fn scale_amount(amount: u64, src_decimals: u8, dst_decimals: u8) -> Option<(u128, u64)> {
+    if dst_decimals >= src_decimals {
+        let factor = 10u128.checked_pow((dst_decimals - src_decimals) as u32)?;
+        Some(((amount as u128).checked_mul(factor)?, 0))
+    } else {
+        let factor = 10u128.checked_pow((src_decimals - dst_decimals) as u32)?;
+        let dust = (amount as u128 % factor) as u64;
+        Some((amount as u128 / factor, dust))
+    }
+}
@@ -16,6 +16,98 @@ pub enum ErrorCode {
     VaultMintMismatch,
     #[msg("Insufficient liquidity to withdraw")] 
     InsufficientLiquidity,
+    #[msg("Caller does not have the required role")]
+    MissingCallerRole,
+    #[msg("Unknown caller role bits")]
+    InvalidCallerRole,
+    #[msg("Too many allowed callers")]
+    TooManyCallers,
+    #[msg("Mint is already in the allowed list")]
+    MintAlreadyAllowed,
+    #[msg("Too many allowed mints")]
+    TooManyMints,
+    #[msg("Vault or LP balance for this mint is not zero")]
+    MintHasBalance,
+    #[msg("Mint is disabled")]
+    MintDisabled,
+    #[msg("Invalid mint config")]
+    InvalidMintConfig,
+    #[msg("Too many destination tokens")]
+    TooManyDstTokens,
+    #[msg("Deposit amount below minimum")]
+    DepositBelowMinimum,
+    #[msg("Deposit amount above maximum")]
+    DepositAboveMaximum,
+    #[msg("Daily outflow cap exceeded")]
+    OutflowCapExceeded,
+    #[msg("Destination token not configured for this mint and dst_eid")]
+    DstTokenNotAllowed,
+    #[msg("No pending admin proposal")]
+    NoPendingAdmin,
+    #[msg("Invalid pending admin")]
+    InvalidPendingAdmin,
+    #[msg("Operation is paused")]
+    Paused,
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags,
+    #[msg("Amount too small to mint or redeem any LP shares")]
+    ZeroShares,
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    #[msg("Total fee exceeds the maximum")]
+    FeeTooHigh,
+    #[msg("Message sender is not a trusted peer for this source chain")]
+    UntrustedPeer,
+    #[msg("Too many remote peers")]
+    TooManyPeers,
+    #[msg("Malformed cross-chain payload")]
+    InvalidPayload,
+    #[msg("Payload does not match the provided accounts")]
+    PayloadMismatch,
+    #[msg("LayerZero fee is below the quoted fee")]
+    FeeBelowQuote,
+    #[msg("LayerZero quote returned no or malformed data")]
+    InvalidQuote,
+    #[msg("Unsupported cross-chain message version")]
+    UnsupportedMessageVersion,
+    #[msg("EVM address must be 20 bytes left-padded to 32")]
+    InvalidEvmAddress,
+    #[msg("Route is disabled")]
+    RouteDisabled,
+    #[msg("Merchant is not allowed on this route")]
+    MerchantNotAllowed,
+    #[msg("Invalid route configuration")]
+    InvalidRouteConfig,
+    #[msg("Payment is not awaiting acknowledgement")]
+    PaymentNotPending,
+    #[msg("Payment has not failed")]
+    PaymentNotFailed,
+    #[msg("Refund timeout has not been reached")]
+    RefundTimeoutNotReached,
+    #[msg("Invalid refund policy")]
+    InvalidRefundPolicy,
+    #[msg("Mint has an unsupported Token-2022 extension")]
+    UnsupportedMintExtension,
+    #[msg("LayerZero OApp program does not match config")]
+    InvalidOAppProgram,
+    #[msg("LayerZero Endpoint program does not match config")]
+    InvalidEndpointProgram,
+    #[msg("LayerZero peer does not match the route's peer")]
+    InvalidPeer,
+    #[msg("LayerZero store does not match the OApp program's Store PDA")]
+    InvalidStore,
+    #[msg("LayerZero endpoint does not match the Endpoint program's Endpoint PDA")]
+    InvalidEndpoint,
+    #[msg("LayerZero charged more than the fee paid by the user")]
+    LayerZeroFeeExceeded,
+    #[msg("Paying in LZ token requires the LZ token mint, token accounts and program")]
+    LzTokenAccountsMissing,
+    #[msg("Invalid LZ token fee account")]
+    InvalidLzTokenAccount,
+    #[msg("LayerZero options must be well-formed type-3 options")]
+    InvalidOptions,
+    #[msg("LayerZero options must set lzReceive gas")]
+    MissingLzReceiveGas,
+    #[msg("LayerZero did not charge the native fee paid by the user")]
+    LayerZeroFeeNotCharged,
 }
-
-
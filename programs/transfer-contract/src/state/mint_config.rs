@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+
+use super::ErrorCode;
+
+pub const MAX_DST_TOKENS: usize = 8;
+pub const OUTFLOW_WINDOW_SECS: i64 = 24 * 60 * 60;
+pub const BPS_DENOMINATOR: u64 = 10_000;
+/// protocol_fee_bps + lp_fee_bps 的上限（10%）
+pub const MAX_TOTAL_FEE_BPS: u16 = 1_000;
+
+/// 每个允许的 mint 一个配置 PDA：seeds = [CONFIG_SEED, config, mint]
+#[account]
+pub struct MintConfig {
+    pub mint: Pubkey,
+    pub enabled: bool,
+    /// 添加时 mint 的 decimals 快照
+    pub decimals: u8,
+    pub min_deposit: u64,
+    /// 0 表示不限制
+    pub max_deposit: u64,
+    /// 每 24 小时从金库流出（transfer_out + lp_withdraw）的上限，0 表示不限制
+    pub daily_outflow_cap: u64,
+    pub outflow_window_start: i64,
+    pub outflow_in_window: u64,
+    /// LP 份额 mint（seeds = [LP_MINT_SEED, config, mint]，mint authority = vault_authority）
+    pub lp_mint: Pubkey,
+    /// LayerZero 目标链（dst_eid）上对应的代币地址
+    pub dst_tokens: Vec<DstToken>,
+    pub bump: u8,
+    /// 仅对该 mint 生效的暂停开关，见 pause 模块
+    pub paused: u8,
+    /// deposit_from_user 收取的协议手续费，转入 treasury
+    pub protocol_fee_bps: u16,
+    /// deposit_from_user 收取的 LP 手续费，留在金库中由 LP 分享
+    pub lp_fee_bps: u16,
+    /// 换算到目标链 decimals 后的尾差：true 退还给用户（不转入金库），false 留在金库归 LP
+    pub refund_dust: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DstToken {
+    pub dst_eid: u32,
+    /// EVM 地址，32 字节，左边补 12 个 0
+    pub token: [u8; 32],
+    /// 目标链代币的 decimals，跨链消息中的金额按它计价
+    pub decimals: u8,
+}
+
+impl DstToken {
+    pub const SIZE: usize = 4 + 32 + 1;
+}
+
+/// add_allowed_mint / update_mint_config 共用的参数
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MintConfigParams {
+    pub enabled: bool,
+    pub min_deposit: u64,
+    pub max_deposit: u64,
+    pub daily_outflow_cap: u64,
+    pub dst_tokens: Vec<DstToken>,
+    pub protocol_fee_bps: u16,
+    pub lp_fee_bps: u16,
+    pub refund_dust: bool,
+}
+
+/// 一笔入金的手续费拆分：protocol_fee + lp_fee + net_amount == amount
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DepositFees {
+    pub protocol_fee: u64,
+    pub lp_fee: u64,
+    pub net_amount: u64,
+}
+
+impl MintConfig {
+    pub const SPACE: usize = 8
+        + 32
+        + 1
+        + 1
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + 32
+        + 4 + (DstToken::SIZE * MAX_DST_TOKENS)
+        + 1
+        + 1
+        + 2
+        + 2
+        + 1;
+
+    pub fn apply(&mut self, params: MintConfigParams) -> Result<()> {
+        require!(params.dst_tokens.len() <= MAX_DST_TOKENS, ErrorCode::TooManyDstTokens);
+        require!(
+            params.max_deposit == 0 || params.min_deposit <= params.max_deposit,
+            ErrorCode::InvalidMintConfig
+        );
+        require!(
+            params.protocol_fee_bps as u32 + params.lp_fee_bps as u32 <= MAX_TOTAL_FEE_BPS as u32,
+            ErrorCode::FeeTooHigh
+        );
+        self.enabled = params.enabled;
+        self.min_deposit = params.min_deposit;
+        self.max_deposit = params.max_deposit;
+        self.daily_outflow_cap = params.daily_outflow_cap;
+        self.dst_tokens = params.dst_tokens;
+        self.protocol_fee_bps = params.protocol_fee_bps;
+        self.lp_fee_bps = params.lp_fee_bps;
+        self.refund_dust = params.refund_dust;
+        Ok(())
+    }
+
+    /// 手续费向下取整，余下部分即跨链消息里的到账金额
+    pub fn deposit_fees(&self, amount: u64) -> Result<DepositFees> {
+        let bps = |fee_bps: u16| -> Result<u64> {
+            let fee = (amount as u128) * (fee_bps as u128) / (BPS_DENOMINATOR as u128);
+            u64::try_from(fee).map_err(|_| error!(ErrorCode::MathOverflow))
+        };
+        let protocol_fee = bps(self.protocol_fee_bps)?;
+        let lp_fee = bps(self.lp_fee_bps)?;
+        let net_amount = amount
+            .checked_sub(protocol_fee)
+            .and_then(|a| a.checked_sub(lp_fee))
+            .ok_or(ErrorCode::MathOverflow)?;
+        require!(net_amount > 0, ErrorCode::DepositBelowMinimum);
+        Ok(DepositFees { protocol_fee, lp_fee, net_amount })
+    }
+
+    pub fn dst_token(&self, dst_eid: u32) -> Option<&DstToken> {
+        self.dst_tokens.iter().find(|t| t.dst_eid == dst_eid)
+    }
+
+    pub fn check_deposit(&self, amount: u64) -> Result<()> {
+        require!(amount >= self.min_deposit, ErrorCode::DepositBelowMinimum);
+        require!(self.max_deposit == 0 || amount <= self.max_deposit, ErrorCode::DepositAboveMaximum);
+        Ok(())
+    }
+
+    /// 记录一笔金库流出，超过当日上限则拒绝
+    pub fn record_outflow(&mut self, amount: u64, now: i64) -> Result<()> {
+        if now.saturating_sub(self.outflow_window_start) >= OUTFLOW_WINDOW_SECS {
+            self.outflow_window_start = now;
+            self.outflow_in_window = 0;
+        }
+        let total = self.outflow_in_window.checked_add(amount).ok_or(ErrorCode::OutflowCapExceeded)?;
+        require!(self.daily_outflow_cap == 0 || total <= self.daily_outflow_cap, ErrorCode::OutflowCapExceeded);
+        self.outflow_in_window = total;
+        Ok(())
+    }
+}
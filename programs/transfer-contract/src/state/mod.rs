@@ -1,32 +1,98 @@
 use anchor_lang::prelude::*;
 
+use crate::instructions::deposit_from_user::{endpoint_pda, store_pda};
+
 pub const CONFIG_SEED: &[u8] = b"config";
 pub const VAULT_SEED: &[u8] = b"vault";
-pub const LP_SEED: &[u8] = b"lp";
-pub const MAX_ALLOWED_MINTS: usize = 3;
+pub const LP_MINT_SEED: &[u8] = b"lp_mint";
+pub const RECEIVED_SEED: &[u8] = b"received";
+pub const ROUTE_SEED: &[u8] = b"route";
+pub const RECEIPT_SEED: &[u8] = b"receipt";
+pub const MAX_ALLOWED_CALLERS: usize = 8;
+pub const MAX_REMOTE_PEERS: usize = 8;
+pub const DEFAULT_REFUND_TIMEOUT_SECS: i64 = 24 * 60 * 60;
 
 #[account]//这个config pda要存的数据，是自己定义的。
 pub struct Config {
     pub admin: Pubkey,
     pub allowed_caller_authority: Pubkey,
-    pub allowed_mints: Vec<Pubkey>,
     pub vault_authority_bump: u8,
+    /// 额外的 CPI 调用方及其角色（见 policy 模块）
+    pub allowed_callers: Vec<AllowedCaller>,
+    /// 两步移交中的候选 admin，Pubkey::default() 表示没有进行中的提议
+    pub pending_admin: Pubkey,
+    /// 可以暂停（但不能恢复）操作的紧急密钥，Pubkey::default() 表示未设置
+    pub guardian: Pubkey,
+    /// 全局暂停开关，见 pause 模块
+    pub paused: u8,
+    /// 协议手续费接收方（treasury 代币账户的 owner）
+    pub treasury: Pubkey,
+    /// lz_receive 信任的源链发送方（见 peer 模块）
+    pub remote_peers: Vec<RemotePeer>,
+    /// 下一笔 deposit_from_user 的 PaymentReceipt 编号，只增不减
+    pub next_receipt_id: u64,
+    /// 付款发出多久后 admin 可以判定失败
+    pub refund_timeout_secs: i64,
+    /// claim_refund 按 net_amount 收取的手续费，留在金库归 LP
+    pub refund_fee_bps: u16,
+    /// 金库编号：config 的 seeds = [CONFIG_SEED, vault_id (BE)]，其余 PDA 都以 config 地址为前缀
+    pub vault_id: u64,
+    pub bump: u8,
+    /// LayerZero OApp 程序：deposit_from_user / quote_deposit 的 CPI 目标，也是 lz_receive 中 Store 签名方的所属程序
+    pub layerzero_oapp_program: Pubkey,
+    /// LayerZero Endpoint 程序
+    pub layerzero_endpoint_program: Pubkey,
+    /// OApp 的 Store PDA，随 layerzero_oapp_program 一起写入，入金时不再逐笔推导
+    pub lz_store: Pubkey,
+    /// Endpoint 的全局 Endpoint PDA，随 layerzero_endpoint_program 一起写入
+    pub lz_endpoint: Pubkey,
 }
 
 impl Config {
-    pub fn is_allowed_mint(&self, mint: &Pubkey) -> bool {
-        self.allowed_mints.iter().any(|m| m == mint)
+    pub const SPACE: usize = 8
+        + 32
+        + 32
+        + 1
+        + 4 + (AllowedCaller::SIZE * MAX_ALLOWED_CALLERS)
+        + 32
+        + 32
+        + 1
+        + 32
+        + 4 + (RemotePeer::SIZE * MAX_REMOTE_PEERS)
+        + 8
+        + 8
+        + 2
+        + 8
+        + 1
+        + 32
+        + 32
+        + 32
+        + 32;
+
+    /// 切换 LayerZero 程序，同时缓存由它们推导的 Store / Endpoint PDA
+    pub fn set_layerzero_programs(&mut self, oapp_program: Pubkey, endpoint_program: Pubkey) {
+        self.layerzero_oapp_program = oapp_program;
+        self.layerzero_endpoint_program = endpoint_program;
+        self.lz_store = store_pda(&oapp_program);
+        self.lz_endpoint = endpoint_pda(&endpoint_program);
     }
 }
 
+pub mod decimals;
 pub mod error;
+pub mod mint_config;
+pub mod pause;
+pub mod peer;
+pub mod policy;
+pub mod receipt;
+pub mod route;
+pub mod shares;
+pub mod token_ext;
 pub use error::ErrorCode;
-
-#[account]
-pub struct LiquidityPosition {
-    pub owner: Pubkey,
-    pub mint: Pubkey,
-    pub amount: u64,
-}
-
+pub use mint_config::{DepositFees, DstToken, MintConfig, MintConfigParams};
+pub use pause::{PAUSE_ALL, PAUSE_CROSS_CHAIN, PAUSE_DEPOSITS, PAUSE_LP_WITHDRAWALS, PAUSE_PAYOUTS};
+pub use peer::{ReceivedMessage, RemotePeer};
+pub use policy::{AllowedCaller, ROLE_PAYOUT, ALL_ROLES};
+pub use receipt::{PaymentReceipt, PaymentStatus};
+pub use route::{ExecutorConfig, Route, RouteParams, RouteToken};
 
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use super::{Config, ErrorCode, MintConfig};
+
+// 暂停开关（位掩码），Config.paused 为全局，MintConfig.paused 为单个 mint
+pub const PAUSE_DEPOSITS: u8 = 1 << 0;
+pub const PAUSE_PAYOUTS: u8 = 1 << 1;
+pub const PAUSE_LP_WITHDRAWALS: u8 = 1 << 2;
+pub const PAUSE_CROSS_CHAIN: u8 = 1 << 3;
+pub const PAUSE_ALL: u8 = PAUSE_DEPOSITS | PAUSE_PAYOUTS | PAUSE_LP_WITHDRAWALS | PAUSE_CROSS_CHAIN;
+
+impl Config {
+    /// admin 与 guardian 都可以暂停；只有 admin 可以恢复
+    pub fn can_pause(&self, signer: &Pubkey) -> bool {
+        *signer == self.admin || (self.guardian != Pubkey::default() && *signer == self.guardian)
+    }
+
+    /// operation 可以是多个开关的组合，任一被全局或该 mint 暂停即拒绝
+    pub fn require_not_paused(&self, mint_config: &MintConfig, operation: u8) -> Result<()> {
+        let paused = (self.paused | mint_config.paused) & operation;
+        if paused != 0 {
+            msg!("operation paused: flags={:#06b} mint={}", paused, mint_config.mint);
+            return err!(ErrorCode::Paused);
+        }
+        Ok(())
+    }
+}
+
+pub fn validate_pause_flags(flags: u8) -> Result<()> {
+    require!(flags != 0 && flags & !PAUSE_ALL == 0, ErrorCode::InvalidPauseFlags);
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use super::{Config, ErrorCode, MAX_REMOTE_PEERS};
+
+/// 某条源链（src_eid）上被信任的 OApp 发送方
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RemotePeer {
+    pub src_eid: u32,
+    /// EVM 地址，32 字节，左边补 12 个 0
+    pub sender: [u8; 32],
+}
+
+impl RemotePeer {
+    pub const SIZE: usize = 4 + 32;
+}
+
+/// 已处理的入站消息，用于去重：seeds = [RECEIVED_SEED, config, src_eid, sender, nonce]
+#[account]
+pub struct ReceivedMessage {
+    pub guid: [u8; 32],
+    pub bump: u8,
+}
+
+impl ReceivedMessage {
+    pub const SPACE: usize = 8 + 32 + 1;
+}
+
+impl Config {
+    pub fn require_remote_peer(&self, src_eid: u32, sender: &[u8; 32]) -> Result<()> {
+        let trusted = self
+            .remote_peers
+            .iter()
+            .any(|p| p.src_eid == src_eid && p.sender == *sender);
+        require!(trusted, ErrorCode::UntrustedPeer);
+        Ok(())
+    }
+
+    /// 每条源链只有一个 peer；sender 全 0 表示移除
+    pub fn set_remote_peer(&mut self, src_eid: u32, sender: [u8; 32]) -> Result<()> {
+        let existing = self.remote_peers.iter().position(|p| p.src_eid == src_eid);
+        match (existing, sender == [0u8; 32]) {
+            (Some(i), true) => {
+                self.remote_peers.remove(i);
+            }
+            (Some(i), false) => self.remote_peers[i].sender = sender,
+            (None, true) => {}
+            (None, false) => {
+                require!(self.remote_peers.len() < MAX_REMOTE_PEERS, ErrorCode::TooManyPeers);
+                self.remote_peers.push(RemotePeer { src_eid, sender });
+            }
+        }
+        Ok(())
+    }
+}
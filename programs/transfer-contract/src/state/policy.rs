@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use super::{Config, ErrorCode, MAX_ALLOWED_CALLERS};
+
+// 调用方角色（位掩码）
+/// 允许从金库打款（transfer_out 以及之后新增的打款路径）
+pub const ROLE_PAYOUT: u8 = 1 << 0;
+pub const ALL_ROLES: u8 = ROLE_PAYOUT;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AllowedCaller {
+    pub authority: Pubkey,
+    pub roles: u8,
+}
+
+impl AllowedCaller {
+    pub const SIZE: usize = 32 + 1;
+}
+
+impl Config {
+    /// signer 拥有的角色；admin 和 allowed_caller_authority 拥有全部角色
+    pub fn caller_roles(&self, authority: &Pubkey) -> u8 {
+        if *authority == self.admin || *authority == self.allowed_caller_authority {
+            return ALL_ROLES;
+        }
+        self.allowed_callers
+            .iter()
+            .find(|c| c.authority == *authority)
+            .map(|c| c.roles)
+            .unwrap_or(0)
+    }
+
+    /// 所有打款路径统一使用的授权检查，返回 signer 的角色以便写入事件
+    pub fn authorize(&self, authority: &Pubkey, role: u8) -> Result<u8> {
+        let roles = self.caller_roles(authority);
+        if roles & role == role {
+            return Ok(roles);
+        }
+        msg!("caller {} denied: roles={:#010b} required={:#010b}", authority, roles, role);
+        if roles == 0 {
+            err!(ErrorCode::NotAuthorized)
+        } else {
+            err!(ErrorCode::MissingCallerRole)
+        }
+    }
+
+    /// 新增/更新调用方角色；roles == 0 表示移除
+    pub fn set_caller_roles(&mut self, authority: Pubkey, roles: u8) -> Result<()> {
+        require!(roles & !ALL_ROLES == 0, ErrorCode::InvalidCallerRole);
+        let existing = self.allowed_callers.iter().position(|c| c.authority == authority);
+        match (existing, roles) {
+            (Some(i), 0) => {
+                self.allowed_callers.remove(i);
+            }
+            (Some(i), _) => self.allowed_callers[i].roles = roles,
+            (None, 0) => {}
+            (None, _) => {
+                require!(self.allowed_callers.len() < MAX_ALLOWED_CALLERS, ErrorCode::TooManyCallers);
+                self.allowed_callers.push(AllowedCaller { authority, roles });
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use super::mint_config::BPS_DENOMINATOR;
+use super::ErrorCode;
+
+/// 出站付款的生命周期
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PaymentStatus {
+    /// 消息已交给 LayerZero
+    Sent,
+    /// 目标链确认到账
+    Delivered,
+    /// 目标链确认失败（或超时后由 admin 判定失败），可申请退款
+    Failed,
+    /// 已退款
+    Refunded,
+}
+
+/// 每笔 deposit_from_user 一个回执：seeds = [RECEIPT_SEED, config, id (BE)]，id 取自 Config.next_receipt_id
+#[account]
+pub struct PaymentReceipt {
+    pub id: u64,
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    /// 用户请求入金的总额（源链 decimals）
+    pub amount: u64,
+    /// 扣除手续费与 dust 后实际跨链的金额（源链 decimals）
+    pub net_amount: u64,
+    /// 跨链消息中的金额（目标链 decimals）
+    pub scaled_amount: u128,
+    pub dst_eid: u32,
+    pub dst_token: [u8; 32],
+    pub merchant: [u8; 32],
+    /// keccak256(message)，EVM 侧可用同样方式计算
+    pub message_hash: [u8; 32],
+    pub status: PaymentStatus,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl PaymentReceipt {
+    pub const SPACE: usize = 8
+        + 8
+        + 32
+        + 32
+        + 8
+        + 8
+        + 16
+        + 4
+        + 32
+        + 32
+        + 32
+        + 1
+        + 8
+        + 1;
+
+    /// 目标链回执：成功 → Delivered，失败 → Failed；只处理仍在 Sent 状态的付款
+    pub fn acknowledge(&mut self, success: bool) -> Result<()> {
+        require!(self.status == PaymentStatus::Sent, ErrorCode::PaymentNotPending);
+        self.status = if success { PaymentStatus::Delivered } else { PaymentStatus::Failed };
+        Ok(())
+    }
+
+    /// 超时仍未收到回执时由 admin 判定失败
+    pub fn expire(&mut self, now: i64, timeout_secs: i64) -> Result<()> {
+        require!(self.status == PaymentStatus::Sent, ErrorCode::PaymentNotPending);
+        require!(
+            now >= self.created_at.saturating_add(timeout_secs),
+            ErrorCode::RefundTimeoutNotReached
+        );
+        self.status = PaymentStatus::Failed;
+        Ok(())
+    }
+
+    /// 标记为已退款，返回 (退给用户的金额, 留在金库的退款手续费)；
+    /// 入金时的协议/LP 手续费不退，退款基数为 net_amount
+    pub fn refund(&mut self, refund_fee_bps: u16) -> Result<(u64, u64)> {
+        require!(self.status == PaymentStatus::Failed, ErrorCode::PaymentNotFailed);
+        let fee = (self.net_amount as u128) * (refund_fee_bps as u128) / (BPS_DENOMINATOR as u128);
+        let fee = u64::try_from(fee).map_err(|_| error!(ErrorCode::MathOverflow))?;
+        self.status = PaymentStatus::Refunded;
+        Ok((self.net_amount - fee, fee))
+    }
+}
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+
+use super::ErrorCode;
+use crate::options::OptionsBuilder;
+
+pub const MAX_ROUTE_TOKENS: usize = 8;
+pub const MAX_ROUTE_MERCHANTS: usize = 16;
+
+/// 每个目标链一个路由 PDA：seeds = [ROUTE_SEED, config, dst_eid (BE)]
+#[account]
+pub struct Route {
+    pub dst_eid: u32,
+    pub enabled: bool,
+    /// 源链金额（mint decimals）的下限
+    pub min_amount: u64,
+    /// 0 表示不限制
+    pub max_amount: u64,
+    /// 客户端未传 options 时，按此生成默认的 LayerZero executor options
+    pub executor: ExecutorConfig,
+    /// 允许的 (源 mint, 目标代币) 组合
+    pub tokens: Vec<RouteToken>,
+    /// 允许的商户（EVM 地址），为空表示不限制
+    pub merchants: Vec<[u8; 32]>,
+    pub bump: u8,
+    /// 按 config.layerzero_oapp_program 与 dst_eid 推导的 OApp Peer PDA，set_route / refresh_route_peer 时写入
+    pub peer: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RouteToken {
+    pub mint: Pubkey,
+    /// EVM 地址，32 字节，左边补 12 个 0
+    pub token: [u8; 32],
+}
+
+impl RouteToken {
+    pub const SIZE: usize = 32 + 32;
+}
+
+/// 路由的 executor 配置，金额均为目标链原生代币的最小单位
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct ExecutorConfig {
+    /// 目标链执行 lzReceive 的 gas，必须大于 0
+    pub lz_receive_gas: u128,
+    /// 随 lzReceive 附带的原生代币
+    pub lz_receive_value: u128,
+    /// 给商户地址空投的原生代币，0 表示不空投
+    pub native_drop: u128,
+    /// lzCompose（index 0）的 gas，0 表示不需要 compose
+    pub compose_gas: u128,
+    pub compose_value: u128,
+}
+
+impl ExecutorConfig {
+    pub const SIZE: usize = 16 * 5;
+
+    /// 一笔入金的 type-3 options；native drop 的接收方是该笔入金的商户
+    pub fn options(&self, merchant: &[u8; 32]) -> Vec<u8> {
+        let mut builder = OptionsBuilder::new().lz_receive(self.lz_receive_gas, self.lz_receive_value);
+        if self.native_drop > 0 {
+            builder = builder.native_drop(self.native_drop, *merchant);
+        }
+        if self.compose_gas > 0 {
+            builder = builder.lz_compose(0, self.compose_gas, self.compose_value);
+        }
+        builder.build()
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RouteParams {
+    pub enabled: bool,
+    pub min_amount: u64,
+    pub max_amount: u64,
+    pub executor: ExecutorConfig,
+    pub tokens: Vec<RouteToken>,
+    pub merchants: Vec<[u8; 32]>,
+}
+
+impl Route {
+    pub const SPACE: usize = 8
+        + 4
+        + 1
+        + 8
+        + 8
+        + ExecutorConfig::SIZE
+        + 4 + (RouteToken::SIZE * MAX_ROUTE_TOKENS)
+        + 4 + (32 * MAX_ROUTE_MERCHANTS)
+        + 1
+        + 32;
+
+    pub fn apply(&mut self, params: RouteParams) -> Result<()> {
+        require!(params.executor.lz_receive_gas > 0, ErrorCode::InvalidRouteConfig);
+        require!(params.tokens.len() <= MAX_ROUTE_TOKENS, ErrorCode::InvalidRouteConfig);
+        require!(params.merchants.len() <= MAX_ROUTE_MERCHANTS, ErrorCode::InvalidRouteConfig);
+        require!(
+            params.max_amount == 0 || params.min_amount <= params.max_amount,
+            ErrorCode::InvalidRouteConfig
+        );
+        self.enabled = params.enabled;
+        self.min_amount = params.min_amount;
+        self.max_amount = params.max_amount;
+        self.executor = params.executor;
+        self.tokens = params.tokens;
+        self.merchants = params.merchants;
+        Ok(())
+    }
+
+    /// deposit_from_user 的路由校验
+    pub fn check_deposit(&self, mint: &Pubkey, dst_token: &[u8; 32], merchant: &[u8; 32], amount: u64) -> Result<()> {
+        require!(self.enabled, ErrorCode::RouteDisabled);
+        require!(
+            self.tokens.iter().any(|t| t.mint == *mint && t.token == *dst_token),
+            ErrorCode::DstTokenNotAllowed
+        );
+        require!(
+            self.merchants.is_empty() || self.merchants.contains(merchant),
+            ErrorCode::MerchantNotAllowed
+        );
+        require!(amount >= self.min_amount, ErrorCode::DepositBelowMinimum);
+        require!(self.max_amount == 0 || amount <= self.max_amount, ErrorCode::DepositAboveMaximum);
+        Ok(())
+    }
+}
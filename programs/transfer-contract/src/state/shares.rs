@@ -0,0 +1,25 @@
+//! LP 份额换算。金库里某个 mint 的全部余额即 LP 的总资产：
+//! transfer_out 打款会按比例稀释所有份额，入金手续费则按比例增厚。
+//!
+//! 与 ERC-4626 的 decimals offset 一样，在总份额上加 10^3、总资产上加 1 个“虚拟”单位：
+//! 首笔存入每单位资产铸造 10^3 份额，攻击者向金库直接转账抬高份额价格时，
+//! 捐赠绝大部分归虚拟份额所有，后续存款人的取整损失不超过约 1/10^3。
+
+const VIRTUAL_SHARES: u128 = 1_000;
+const VIRTUAL_ASSETS: u128 = 1;
+
+/// 存入 amount 可以铸造的份额（向下取整）
+pub fn shares_for_deposit(amount: u64, total_assets: u64, total_shares: u64) -> Option<u64> {
+    let shares = (amount as u128)
+        .checked_mul(total_shares as u128 + VIRTUAL_SHARES)?
+        / (total_assets as u128 + VIRTUAL_ASSETS);
+    u64::try_from(shares).ok()
+}
+
+/// 销毁 shares 可以取回的资产（向下取整）
+pub fn assets_for_shares(shares: u64, total_assets: u64, total_shares: u64) -> Option<u64> {
+    let assets = (shares as u128)
+        .checked_mul(total_assets as u128 + VIRTUAL_ASSETS)?
+        / (total_shares as u128 + VIRTUAL_SHARES);
+    u64::try_from(assets).ok()
+}
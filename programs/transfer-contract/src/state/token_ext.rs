@@ -0,0 +1,59 @@
+//! Token-2022 mint 扩展的处理。SPL Token 的 mint 没有扩展，这里的检查直接通过。
+//!
+//! - 转账手续费（TransferFeeConfig）会让收款方少收，入金按实际到账金额记账；
+//! - 不可转让、永久代理、机密转账会破坏金库的余额假设，这类 mint 不允许加入白名单；
+//! - 转账钩子（TransferHook）要求每次转账附带钩子程序的额外账户，金库的转账 CPI 不传这些账户，
+//!   且钩子程序可以随时拒绝转出，同样不允许加入；
+//! - 默认账户状态为冻结（DefaultAccountState = Frozen）时，新建的金库 ATA、LP ATA 与收款人 ATA
+//!   都需冻结权限方逐个解冻才能转账，不允许加入；默认为 Initialized 的 mint 不受影响。
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022;
+use spl_token_2022::extension::default_account_state::DefaultAccountState;
+use spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use spl_token_2022::extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions};
+
+use spl_token_2022::state::AccountState;
+
+use super::ErrorCode;
+
+/// 金库无法安全持有的扩展
+pub const UNSUPPORTED_MINT_EXTENSIONS: [ExtensionType; 6] = [
+    ExtensionType::NonTransferable,
+    ExtensionType::PermanentDelegate,
+    ExtensionType::TransferHook,
+    ExtensionType::ConfidentialTransferMint,
+    ExtensionType::ConfidentialTransferFeeConfig,
+    ExtensionType::ConfidentialMintBurn,
+];
+
+/// add_allowed_mint 时调用：mint 带有不支持的扩展则拒绝
+pub fn require_supported_mint(mint: &AccountInfo) -> Result<()> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(());
+    }
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    let unsupported = state
+        .get_extension_types()?
+        .into_iter()
+        .any(|t| UNSUPPORTED_MINT_EXTENSIONS.contains(&t));
+    require!(!unsupported, ErrorCode::UnsupportedMintExtension);
+    if let Ok(default_state) = state.get_extension::<DefaultAccountState>() {
+        require!(default_state.state != AccountState::Frozen as u8, ErrorCode::UnsupportedMintExtension);
+    }
+    Ok(())
+}
+
+/// 按 mint 的 TransferFeeConfig 计算一次转账 amount 时收款方被扣的手续费；没有该扩展时为 0
+pub fn transfer_fee(mint: &AccountInfo, amount: u64, epoch: u64) -> Result<u64> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(0);
+    }
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    match state.get_extension::<TransferFeeConfig>() {
+        Ok(config) => Ok(config.calculate_epoch_fee(epoch, amount).ok_or(ErrorCode::MathOverflow)?),
+        Err(_) => Ok(0),
+    }
+}
@@ -0,0 +1,98 @@
+mod common;
+
+use anchor_lang::prelude::*;
+
+use common::*;
+use transfer_contract::state::{Config, ErrorCode};
+use transfer_contract::transfer_contract as program;
+use transfer_contract::{AcceptAdmin, CancelAdminProposal, ProposeAdmin};
+
+fn config_info(config: &Config) -> AccountInfo<'static> {
+    anchor_account_info(config_pda(config.vault_id).0, config)
+}
+
+fn propose_admin(config: &Config, signer: Pubkey, new_admin: Pubkey) -> Result<Config> {
+    let args = new_admin.try_to_vec().unwrap();
+    run_handler(&transfer_contract::ID, vec![config_info(config), signer_info(signer)], &args, |ctx| {
+        let Context { program_id, accounts, remaining_accounts, bumps, .. } = ctx;
+        let ctx = Context::<ProposeAdmin>::new(program_id, &mut *accounts, remaining_accounts, bumps);
+        program::propose_admin(ctx, new_admin)?;
+        Ok((*accounts.config).clone())
+    })
+}
+
+fn accept_admin(config: &Config, signer: Pubkey) -> Result<Config> {
+    run_handler(&transfer_contract::ID, vec![config_info(config), signer_info(signer)], &[], |ctx| {
+        let Context { program_id, accounts, remaining_accounts, bumps, .. } = ctx;
+        program::accept_admin(Context::<AcceptAdmin>::new(program_id, &mut *accounts, remaining_accounts, bumps))?;
+        Ok((*accounts.config).clone())
+    })
+}
+
+fn cancel_admin_proposal(config: &Config, signer: Pubkey) -> Result<Config> {
+    run_handler(&transfer_contract::ID, vec![config_info(config), signer_info(signer)], &[], |ctx| {
+        let Context { program_id, accounts, remaining_accounts, bumps, .. } = ctx;
+        let ctx = Context::<CancelAdminProposal>::new(program_id, &mut *accounts, remaining_accounts, bumps);
+        program::cancel_admin_proposal(ctx)?;
+        Ok((*accounts.config).clone())
+    })
+}
+
+#[test]
+fn only_pending_admin_can_accept() {
+    let config = Fixture::new().config_account;
+    let (admin, candidate) = (config.admin, Pubkey::new_unique());
+    assert_error(accept_admin(&config, candidate), ErrorCode::NoPendingAdmin);
+
+    let config = propose_admin(&config, admin, candidate).unwrap();
+    assert_eq!(config.pending_admin, candidate);
+    assert_eq!(config.admin, admin);
+    assert_error(accept_admin(&config, admin), ErrorCode::NotAuthorized);
+    assert_error(accept_admin(&config, Pubkey::new_unique()), ErrorCode::NotAuthorized);
+
+    let config = accept_admin(&config, candidate).unwrap();
+    assert_eq!(config.admin, candidate);
+    assert_eq!(config.pending_admin, Pubkey::default());
+    // 提议已被消费，不能重复接受
+    assert_error(accept_admin(&config, candidate), ErrorCode::NoPendingAdmin);
+}
+
+#[test]
+fn only_admin_can_propose_valid_candidate() {
+    let config = Fixture::new().config_account;
+    assert_error(propose_admin(&config, Pubkey::new_unique(), Pubkey::new_unique()), ErrorCode::NotAuthorized);
+    assert_error(propose_admin(&config, config.admin, Pubkey::default()), ErrorCode::InvalidPendingAdmin);
+    assert_error(propose_admin(&config, config.admin, config.admin), ErrorCode::InvalidPendingAdmin);
+}
+
+#[test]
+fn cancel_and_repropose() {
+    let config = Fixture::new().config_account;
+    let (admin, first, second) = (config.admin, Pubkey::new_unique(), Pubkey::new_unique());
+    assert_error(cancel_admin_proposal(&config, admin), ErrorCode::NoPendingAdmin);
+
+    let config = propose_admin(&config, admin, first).unwrap();
+    assert_error(cancel_admin_proposal(&config, first), ErrorCode::NotAuthorized);
+    let config = cancel_admin_proposal(&config, admin).unwrap();
+    assert_eq!(config.pending_admin, Pubkey::default());
+    assert_error(accept_admin(&config, first), ErrorCode::NoPendingAdmin);
+
+    // 再次提议覆盖之前的候选
+    let config = propose_admin(&config, admin, first).unwrap();
+    let config = propose_admin(&config, admin, second).unwrap();
+    assert_error(accept_admin(&config, first), ErrorCode::NotAuthorized);
+    assert_eq!(accept_admin(&config, second).unwrap().admin, second);
+}
+
+#[test]
+fn old_admin_loses_rights_after_handover() {
+    let config = Fixture::new().config_account;
+    let (old_admin, new_admin) = (config.admin, Pubkey::new_unique());
+    let config = propose_admin(&config, old_admin, new_admin).unwrap();
+    let config = accept_admin(&config, new_admin).unwrap();
+
+    assert_error(propose_admin(&config, old_admin, Pubkey::new_unique()), ErrorCode::NotAuthorized);
+    assert!(!config.can_pause(&old_admin));
+    assert!(config.can_pause(&new_admin));
+    propose_admin(&config, new_admin, old_admin).unwrap();
+}
@@ -0,0 +1,127 @@
+//! add_allowed_mint / remove_allowed_mint：重复添加、移除前的余额检查（金库 ATA 与 LP 份额）。
+
+mod common;
+
+use anchor_lang::error::ErrorCode as AnchorErrorCode;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_2022;
+
+use common::*;
+use transfer_contract::state::{ErrorCode, MintConfig, MintConfigParams};
+use transfer_contract::{AddAllowedMint, RemoveAllowedMint};
+
+fn mint_params(f: &Fixture) -> MintConfigParams {
+    let m = &f.mint_config_account;
+    MintConfigParams {
+        enabled: m.enabled,
+        min_deposit: m.min_deposit,
+        max_deposit: m.max_deposit,
+        daily_outflow_cap: m.daily_outflow_cap,
+        dst_tokens: m.dst_tokens.clone(),
+        protocol_fee_bps: m.protocol_fee_bps,
+        lp_fee_bps: m.lp_fee_bps,
+        refund_dust: m.refund_dust,
+    }
+}
+
+/// 对已存在的 MintConfig 执行 add_allowed_mint（init_if_needed 要求账户大小等于 MintConfig::SPACE）
+fn add_existing_mint(f: &Fixture, signer: Pubkey) -> Result<()> {
+    let params = mint_params(f);
+    let mut data = Vec::new();
+    f.mint_config_account.try_serialize(&mut data).unwrap();
+    data.resize(MintConfig::SPACE, 0);
+    let infos = vec![
+        f.config_info(),
+        account_info(f.mint_config, transfer_contract::ID, data, false, true, false),
+        signer_info(signer),
+        unchecked_info(f.vault_authority),
+        f.mint_info(),
+        f.lp_mint_info(),
+        f.token_program_info(),
+        program_info(system_program::ID),
+    ];
+    run_handler(&transfer_contract::ID, infos, &params.try_to_vec().unwrap(), |ctx| {
+        let Context { program_id, accounts, remaining_accounts, bumps, .. } = ctx;
+        let ctx = Context::<AddAllowedMint>::new(program_id, &mut *accounts, remaining_accounts, bumps);
+        transfer_contract::transfer_contract::add_allowed_mint(ctx, params)
+    })
+}
+
+fn remove_mint(f: &Fixture, signer: Pubkey, vault: AccountInfo<'static>, lp_supply: u64) -> Result<()> {
+    let infos = vec![
+        f.config_info(),
+        f.mint_config_info(),
+        signer_info(signer),
+        unchecked_info(f.vault_authority),
+        vault,
+        f.mint_info(),
+        program_mint_info(f.token_program, f.lp_mint, Some(f.vault_authority), lp_supply),
+        f.token_program_info(),
+    ];
+    run_handler(&transfer_contract::ID, infos, &[], |ctx| {
+        let Context { program_id, accounts, remaining_accounts, bumps, .. } = ctx;
+        let ctx = Context::<RemoveAllowedMint>::new(program_id, &mut *accounts, remaining_accounts, bumps);
+        transfer_contract::transfer_contract::remove_allowed_mint(ctx)
+    })
+}
+
+fn empty_vault(f: &Fixture) -> AccountInfo<'static> {
+    f.token_account_info(f.vault_token_account, f.mint, f.vault_authority, 0)
+}
+
+#[test]
+fn adding_an_allowed_mint_twice_is_rejected() {
+    let f = Fixture::new();
+    assert_error(add_existing_mint(&f, f.admin), ErrorCode::MintAlreadyAllowed);
+}
+
+#[test]
+fn add_allowed_mint_is_admin_only() {
+    let f = Fixture::new();
+    assert_error(add_existing_mint(&f, f.user), ErrorCode::NotAuthorized);
+}
+
+#[test]
+fn mint_with_empty_vault_and_no_lp_can_be_removed() {
+    for token_program in [anchor_spl::token::ID, token_2022::ID] {
+        let f = Fixture::with_token_program(token_program);
+        if let Err(e) = remove_mint(&f, f.admin, empty_vault(&f), 0) {
+            panic!("remove_allowed_mint rejected: {e:?}");
+        }
+    }
+}
+
+#[test]
+fn missing_vault_ata_counts_as_zero_balance() {
+    let f = Fixture::new();
+    if let Err(e) = remove_mint(&f, f.admin, unchecked_info(f.vault_token_account), 0) {
+        panic!("remove_allowed_mint rejected a missing vault ATA: {e:?}");
+    }
+}
+
+#[test]
+fn mint_with_balance_cannot_be_removed() {
+    let f = Fixture::new();
+    // 金库 ATA 仍有余额
+    assert_error(remove_mint(&f, f.admin, f.vault_info(), 0), ErrorCode::MintHasBalance);
+    // LP 份额未全部赎回
+    assert_error(remove_mint(&f, f.admin, empty_vault(&f), 1), ErrorCode::MintHasBalance);
+}
+
+#[test]
+fn remove_allowed_mint_checks_the_vault_ata() {
+    let f = Fixture::new();
+    // 不是金库 ATA 的代币账户
+    let other = f.token_account_info(Pubkey::new_unique(), f.mint, f.vault_authority, 0);
+    assert_error(remove_mint(&f, f.admin, other, 0), AnchorErrorCode::ConstraintAddress);
+    // 地址正确但不归 token program 所有
+    let fake = account_info(f.vault_token_account, Pubkey::new_unique(), vec![0; 165], false, true, false);
+    assert_error(remove_mint(&f, f.admin, fake, 0), AnchorErrorCode::AccountOwnedByWrongProgram);
+}
+
+#[test]
+fn remove_allowed_mint_is_admin_only() {
+    let f = Fixture::new();
+    assert_error(remove_mint(&f, f.user, empty_vault(&f), 0), ErrorCode::NotAuthorized);
+}
@@ -0,0 +1,59 @@
+mod common;
+
+use anchor_lang::prelude::*;
+
+use common::*;
+use transfer_contract::state::{AllowedCaller, ErrorCode, ALL_ROLES, MAX_ALLOWED_CALLERS, ROLE_PAYOUT};
+
+#[test]
+fn admin_and_caller_authority_have_all_roles() {
+    let mut config = Fixture::new().config_account;
+    let authority = Pubkey::new_unique();
+    config.allowed_caller_authority = authority;
+    assert_eq!(config.authorize(&config.admin, ROLE_PAYOUT).unwrap(), ALL_ROLES);
+    assert_eq!(config.authorize(&authority, ROLE_PAYOUT).unwrap(), ALL_ROLES);
+}
+
+#[test]
+fn registered_caller_needs_the_role() {
+    let mut config = Fixture::new().config_account;
+    let payout = Pubkey::new_unique();
+    let other_role = Pubkey::new_unique();
+    config.set_caller_roles(payout, ROLE_PAYOUT).unwrap();
+    assert_eq!(config.authorize(&payout, ROLE_PAYOUT).unwrap(), ROLE_PAYOUT);
+
+    // 已登记但缺少所需角色（例如由将来新增的角色登记）
+    config.allowed_callers.push(AllowedCaller { authority: other_role, roles: 1 << 7 });
+    assert_error(config.authorize(&other_role, ROLE_PAYOUT), ErrorCode::MissingCallerRole);
+    // 未登记的 signer
+    assert_error(config.authorize(&Pubkey::new_unique(), ROLE_PAYOUT), ErrorCode::NotAuthorized);
+}
+
+#[test]
+fn caller_roles_are_validated_and_capped() {
+    let mut config = Fixture::new().config_account;
+    assert_error(config.set_caller_roles(Pubkey::new_unique(), !ALL_ROLES), ErrorCode::InvalidCallerRole);
+    assert!(config.allowed_callers.is_empty());
+
+    for _ in 0..MAX_ALLOWED_CALLERS {
+        config.set_caller_roles(Pubkey::new_unique(), ROLE_PAYOUT).unwrap();
+    }
+    assert_error(config.set_caller_roles(Pubkey::new_unique(), ROLE_PAYOUT), ErrorCode::TooManyCallers);
+    // 更新已有调用方不受上限影响
+    let existing = config.allowed_callers[0].authority;
+    config.set_caller_roles(existing, ROLE_PAYOUT).unwrap();
+    assert_eq!(config.allowed_callers.len(), MAX_ALLOWED_CALLERS);
+}
+
+#[test]
+fn zero_roles_removes_the_caller() {
+    let mut config = Fixture::new().config_account;
+    let caller = Pubkey::new_unique();
+    config.set_caller_roles(caller, ROLE_PAYOUT).unwrap();
+    config.set_caller_roles(caller, 0).unwrap();
+    assert!(config.allowed_callers.is_empty());
+    assert_error(config.authorize(&caller, ROLE_PAYOUT), ErrorCode::NotAuthorized);
+    // 移除不存在的调用方是 no-op
+    config.set_caller_roles(caller, 0).unwrap();
+    assert!(config.allowed_callers.is_empty());
+}
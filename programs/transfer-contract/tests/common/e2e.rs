@@ -0,0 +1,275 @@
+//! 指向 mock-oapp 的金库，在 LiteSVM（见 svm 模块）上执行完整的指令
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+
+use transfer_contract::instructions::deposit_from_user::{endpoint_pda, peer_pda, store_pda, DepositParams};
+use transfer_contract::instructions::lz_receive::LzReceiveParams;
+use transfer_contract::messaging::{evm_word, Message};
+use transfer_contract::state::{DstToken, RemotePeer, RouteToken, RECEIVED_SEED};
+
+use super::svm::{assert_success, Svm, TransactionResult};
+use super::*;
+
+/// 桩程序 quote 返回的固定报价
+pub const NATIVE_FEE: u64 = 5_000;
+pub const LZ_TOKEN_FEE: u64 = 70;
+/// 目标链上的 OApp（Peer 账户中的地址，也是入站消息的 sender）
+pub const REMOTE_OAPP: [u8; 32] = [0x0a; 32];
+pub const USER_BALANCE: u64 = 1_000_000;
+pub const VAULT_BALANCE: u64 = 1_000_000;
+
+pub fn evm(byte: u8) -> [u8; 32] {
+    evm_word(&[byte; 20])
+}
+
+/// REMOTE_OAPP 从 DST_EID 发来的入站消息
+pub fn inbound(nonce: u64, message: Message) -> LzReceiveParams {
+    LzReceiveParams {
+        src_eid: DST_EID,
+        sender: REMOTE_OAPP,
+        nonce,
+        guid: [nonce as u8; 32],
+        message: message.encode(),
+        extra_data: vec![],
+    }
+}
+
+pub struct Env {
+    pub svm: Svm,
+    pub f: Fixture,
+    pub dst_token: [u8; 32],
+    pub merchant: [u8; 32],
+    pub endpoint_program: Pubkey,
+    pub user_token: Pubkey,
+    pub treasury_token: Pubkey,
+    /// 代为支付入站消息账户租金的 Executor
+    pub executor: Pubkey,
+    /// 追加在 deposit_from_user 末尾的可写账户，桩程序把原生代币手续费转到这里
+    pub fee_receiver: Pubkey,
+}
+
+impl Env {
+    /// 金库指向 mock-oapp；Store / Peer 由桩程序自己的 init_store / set_peer 创建
+    pub fn new(token_program: Pubkey) -> Self {
+        let mut f = Fixture::with_token_program(token_program);
+        let (dst_token, merchant) = (evm(1), evm(2));
+        let endpoint_program = Pubkey::new_unique();
+        f.config_account.set_layerzero_programs(mock_oapp::ID, endpoint_program);
+        f.config_account.remote_peers = vec![RemotePeer { src_eid: DST_EID, sender: REMOTE_OAPP }];
+        f.mint_config_account.protocol_fee_bps = 100;
+        f.mint_config_account.dst_tokens = vec![DstToken { dst_eid: DST_EID, token: dst_token, decimals: DECIMALS }];
+        f.route_account.tokens = vec![RouteToken { mint: f.mint, token: dst_token }];
+        f.route_account.peer = peer_pda(&mock_oapp::ID, DST_EID);
+
+        let (user_token, treasury_token, executor, oapp_admin, fee_receiver) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut svm = Svm::new();
+        svm.load([
+            f.config_info(),
+            f.mint_config_info(),
+            f.route_info(),
+            f.mint_info(),
+            f.token_account_info(f.vault_token_account, f.mint, f.vault_authority, VAULT_BALANCE),
+            f.token_account_info(user_token, f.mint, f.user, USER_BALANCE),
+            f.token_account_info(treasury_token, f.mint, f.config_account.treasury, 0),
+            signer_info(f.user),
+            signer_info(executor),
+            signer_info(oapp_admin),
+            unchecked_info(fee_receiver),
+        ]);
+        svm.add_stub_program(endpoint_program);
+
+        let store = store_pda(&mock_oapp::ID);
+        let init_store = Instruction {
+            program_id: mock_oapp::ID,
+            accounts: mock_oapp::accounts::InitStore { store, admin: oapp_admin, system_program: system_program::ID }
+                .to_account_metas(None),
+            data: mock_oapp::instruction::InitStore { native_fee: NATIVE_FEE, lz_token_fee: LZ_TOKEN_FEE }.data(),
+        };
+        assert_success(svm.process(&init_store));
+        let set_peer = Instruction {
+            program_id: mock_oapp::ID,
+            accounts: mock_oapp::accounts::SetPeer {
+                store,
+                peer: f.route_account.peer,
+                admin: oapp_admin,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: mock_oapp::instruction::SetPeer { dst_eid: DST_EID, address: REMOTE_OAPP }.data(),
+        };
+        assert_success(svm.process(&set_peer));
+
+        Self { svm, f, dst_token, merchant, endpoint_program, user_token, treasury_token, executor, fee_receiver }
+    }
+
+    pub fn params(&self, amount: u64) -> DepositParams {
+        DepositParams {
+            amount,
+            dst_eid: DST_EID,
+            dst_token: self.dst_token,
+            merchant: self.merchant,
+            options: None,
+            native_fee: None,
+            lz_token_fee: None,
+        }
+    }
+
+    pub fn quote_deposit(&mut self, params: DepositParams) -> TransactionResult {
+        let ix = self.quote_deposit_ix(params);
+        self.svm.process(&ix)
+    }
+
+    pub fn quote_deposit_ix(&self, params: DepositParams) -> Instruction {
+        let f = &self.f;
+        let accounts = transfer_contract::accounts::QuoteDeposit {
+            config: f.config,
+            mint: f.mint,
+            mint_config: f.mint_config,
+            route: f.route,
+            layerzero_oapp_program: mock_oapp::ID,
+            peer: f.route_account.peer,
+            store: store_pda(&mock_oapp::ID),
+            endpoint: endpoint_pda(&self.endpoint_program),
+            endpoint_program: self.endpoint_program,
+        };
+        Instruction {
+            program_id: transfer_contract::ID,
+            accounts: accounts.to_account_metas(None),
+            data: transfer_contract::instruction::QuoteDeposit { params }.data(),
+        }
+    }
+
+    pub fn deposit_accounts(&self) -> transfer_contract::accounts::DepositFromUser {
+        let f = &self.f;
+        let next_receipt_id = self.svm.anchor_account::<transfer_contract::state::Config>(&f.config).next_receipt_id;
+        transfer_contract::accounts::DepositFromUser {
+            config: f.config,
+            user: f.user,
+            receipt: receipt_pda(&f.config, next_receipt_id).0,
+            user_source_token: self.user_token,
+            vault_authority: f.vault_authority,
+            vault_token_account: f.vault_token_account,
+            treasury_token_account: self.treasury_token,
+            mint: f.mint,
+            mint_config: f.mint_config,
+            route: f.route,
+            token_program: f.token_program,
+            layerzero_oapp_program: mock_oapp::ID,
+            peer: f.route_account.peer,
+            store: store_pda(&mock_oapp::ID),
+            endpoint: endpoint_pda(&self.endpoint_program),
+            endpoint_program: self.endpoint_program,
+            system_program: system_program::ID,
+            lz_token_mint: None,
+            user_lz_token_account: None,
+            vault_lz_token_account: None,
+            lz_token_program: None,
+        }
+    }
+
+    pub fn deposit_with(
+        &mut self,
+        accounts: transfer_contract::accounts::DepositFromUser,
+        params: DepositParams,
+    ) -> TransactionResult {
+        let mut ix = Instruction {
+            program_id: transfer_contract::ID,
+            accounts: accounts.to_account_metas(None),
+            data: transfer_contract::instruction::DepositFromUser { params }.data(),
+        };
+        ix.accounts.push(AccountMeta::new(self.fee_receiver, false));
+        self.svm.process(&ix)
+    }
+
+    pub fn deposit(&mut self, params: DepositParams) -> TransactionResult {
+        self.deposit_with(self.deposit_accounts(), params)
+    }
+
+    pub fn received_pda(&self, params: &LzReceiveParams) -> Pubkey {
+        let seeds: &[&[u8]] = &[
+            RECEIVED_SEED,
+            self.f.config.as_ref(),
+            &params.src_eid.to_be_bytes(),
+            &params.sender,
+            &params.nonce.to_be_bytes(),
+        ];
+        Pubkey::find_program_address(seeds, &transfer_contract::ID).0
+    }
+
+    /// 由桩程序以 Store 签名投递；未经 OApp 时 Store 无法签名
+    pub fn deliver(&mut self, ix: Instruction) -> TransactionResult {
+        let store = store_pda(&mock_oapp::ID);
+        let mut accounts = mock_oapp::accounts::Deliver { store, receiver_program: transfer_contract::ID }
+            .to_account_metas(None);
+        accounts.extend(ix.accounts.into_iter().map(|mut meta| {
+            meta.is_signer &= meta.pubkey != store;
+            meta
+        }));
+        let deliver = Instruction {
+            program_id: mock_oapp::ID,
+            accounts,
+            data: mock_oapp::instruction::Deliver { data: ix.data }.data(),
+        };
+        self.svm.process(&deliver)
+    }
+
+    pub fn lz_receive_ix(&self, recipient: Pubkey, params: LzReceiveParams) -> Instruction {
+        let f = &self.f;
+        let accounts = transfer_contract::accounts::LzReceive {
+            config: f.config,
+            layerzero_oapp_program: mock_oapp::ID,
+            oapp_store: store_pda(&mock_oapp::ID),
+            payer: self.executor,
+            received_message: self.received_pda(&params),
+            vault_authority: f.vault_authority,
+            vault_token_account: f.vault_token_account,
+            recipient,
+            recipient_token_account: get_associated_token_address_with_program_id(&recipient, &f.mint, &f.token_program),
+            mint: f.mint,
+            mint_config: f.mint_config,
+            token_program: f.token_program,
+            system_program: system_program::ID,
+        };
+        Instruction {
+            program_id: transfer_contract::ID,
+            accounts: accounts.to_account_metas(None),
+            data: transfer_contract::instruction::LzReceive { params }.data(),
+        }
+    }
+
+    pub fn lz_receive_ack_ix(&self, receipt: Pubkey, params: LzReceiveParams) -> Instruction {
+        let f = &self.f;
+        let accounts = transfer_contract::accounts::LzReceiveAck {
+            config: f.config,
+            layerzero_oapp_program: mock_oapp::ID,
+            oapp_store: store_pda(&mock_oapp::ID),
+            payer: self.executor,
+            received_message: self.received_pda(&params),
+            receipt,
+            system_program: system_program::ID,
+        };
+        Instruction {
+            program_id: transfer_contract::ID,
+            accounts: accounts.to_account_metas(None),
+            data: transfer_contract::instruction::LzReceiveAck { params }.data(),
+        }
+    }
+
+    /// recipient 名下的 ATA（余额为 0）
+    pub fn recipient(&mut self) -> Pubkey {
+        let recipient = Pubkey::new_unique();
+        let ata = get_associated_token_address_with_program_id(&recipient, &self.f.mint, &self.f.token_program);
+        let info = self.f.token_account_info(ata, self.f.mint, recipient, 0);
+        self.svm.load([info]);
+        recipient
+    }
+}
@@ -0,0 +1,417 @@
+#![allow(dead_code)]
+
+use std::collections::BTreeSet;
+use std::sync::Once;
+
+use anchor_lang::prelude::*;
+use solana_sysvar::program_stubs::{set_syscall_stubs, SyscallStubs};
+use anchor_lang::{system_program, Accounts, Bumps};
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token::spl_token;
+use anchor_spl::token::spl_token::solana_program::program_option::COption;
+use anchor_spl::token::spl_token::solana_program::program_pack::Pack;
+
+use transfer_contract::instructions::deposit_from_user::{
+    endpoint_pda, peer_pda, store_pda, LAYERZERO_ENDPOINT_PROGRAM_ID, LAYERZERO_OAPP_PROGRAM_ID,
+};
+use transfer_contract::state::{
+    Config, DstToken, ExecutorConfig, MintConfig, PaymentReceipt, PaymentStatus, Route, RouteToken, CONFIG_SEED, LP_MINT_SEED, RECEIPT_SEED, ROUTE_SEED, VAULT_SEED,
+};
+
+#[cfg(feature = "test-sbf")]
+pub mod e2e;
+#[cfg(feature = "test-sbf")]
+pub mod svm;
+
+pub const DECIMALS: u8 = 6;
+pub const LAMPORTS: u64 = 1_000_000_000;
+pub const DST_EID: u32 = 30101;
+pub const VAULT_ID: u64 = 1;
+pub const DST_TOKEN: [u8; 32] = [1u8; 32];
+pub const LZ_RECEIVE_GAS: u128 = 200_000;
+
+/// 测试用账户：构造出的 AccountInfo 生命周期为 'static（测试进程内泄漏即可）
+pub fn account_info(
+    key: Pubkey,
+    owner: Pubkey,
+    data: Vec<u8>,
+    is_signer: bool,
+    is_writable: bool,
+    executable: bool,
+) -> AccountInfo<'static> {
+    AccountInfo::new(
+        Box::leak(Box::new(key)),
+        is_signer,
+        is_writable,
+        Box::leak(Box::new(LAMPORTS)),
+        Box::leak(data.into_boxed_slice()),
+        Box::leak(Box::new(owner)),
+        executable,
+        0,
+    )
+}
+
+pub fn program_info(id: Pubkey) -> AccountInfo<'static> {
+    account_info(id, Pubkey::default(), vec![], false, false, true)
+}
+
+pub fn signer_info(key: Pubkey) -> AccountInfo<'static> {
+    account_info(key, system_program::ID, vec![], true, true, false)
+}
+
+pub fn unchecked_info(key: Pubkey) -> AccountInfo<'static> {
+    account_info(key, Pubkey::default(), vec![], false, false, false)
+}
+
+pub fn anchor_account_info<T: AccountSerialize>(key: Pubkey, value: &T) -> AccountInfo<'static> {
+    let mut data = Vec::new();
+    value.try_serialize(&mut data).unwrap();
+    account_info(key, transfer_contract::ID, data, false, true, false)
+}
+
+pub fn mint_info(key: Pubkey) -> AccountInfo<'static> {
+    mint_info_with_authority(key, None, 1_000_000_000)
+}
+
+pub fn mint_info_with_authority(key: Pubkey, authority: Option<Pubkey>, supply: u64) -> AccountInfo<'static> {
+    program_mint_info(spl_token::ID, key, authority, supply)
+}
+
+/// Token-2022 无扩展的 mint / 代币账户与 SPL Token 布局相同，只有 owner 不同
+pub fn program_mint_info(
+    token_program: Pubkey,
+    key: Pubkey,
+    authority: Option<Pubkey>,
+    supply: u64,
+) -> AccountInfo<'static> {
+    let mut data = vec![0u8; spl_token::state::Mint::LEN];
+    spl_token::state::Mint {
+        mint_authority: authority.map(COption::Some).unwrap_or(COption::None),
+        supply,
+        decimals: DECIMALS,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    }
+    .pack_into_slice(&mut data);
+    account_info(key, token_program, data, false, true, false)
+}
+
+pub fn token_account_info(key: Pubkey, mint: Pubkey, owner: Pubkey, amount: u64) -> AccountInfo<'static> {
+    program_token_account_info(spl_token::ID, key, mint, owner, amount)
+}
+
+pub fn program_token_account_info(
+    token_program: Pubkey,
+    key: Pubkey,
+    mint: Pubkey,
+    owner: Pubkey,
+    amount: u64,
+) -> AccountInfo<'static> {
+    let mut data = vec![0u8; spl_token::state::Account::LEN];
+    spl_token::state::Account {
+        mint,
+        owner,
+        amount,
+        delegate: COption::None,
+        state: spl_token::state::AccountState::Initialized,
+        is_native: COption::None,
+        delegated_amount: 0,
+        close_authority: COption::None,
+    }
+    .pack_into_slice(&mut data);
+    account_info(key, token_program, data, false, true, false)
+}
+
+pub fn config_pda(vault_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONFIG_SEED, &vault_id.to_be_bytes()], &transfer_contract::ID)
+}
+
+pub fn vault_authority_pda(config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_SEED, config.as_ref()], &transfer_contract::ID)
+}
+
+pub fn mint_config_pda(config: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONFIG_SEED, config.as_ref(), mint.as_ref()], &transfer_contract::ID)
+}
+
+pub fn lp_mint_pda(config: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[LP_MINT_SEED, config.as_ref(), mint.as_ref()], &transfer_contract::ID)
+}
+
+pub fn route_pda(config: &Pubkey, dst_eid: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ROUTE_SEED, config.as_ref(), &dst_eid.to_be_bytes()], &transfer_contract::ID)
+}
+
+pub fn receipt_pda(config: &Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RECEIPT_SEED, config.as_ref(), &id.to_be_bytes()], &transfer_contract::ID)
+}
+
+pub fn vault_ata(vault_authority: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(vault_authority, mint, token_program)
+}
+
+/// 一个已初始化、只允许 `mint` 的金库；`mint` 及所有代币账户归 `token_program` 所有
+pub struct Fixture {
+    pub token_program: Pubkey,
+    pub admin: Pubkey,
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub config: Pubkey,
+    pub vault_authority: Pubkey,
+    pub vault_token_account: Pubkey,
+    pub config_account: Config,
+    pub mint_config: Pubkey,
+    pub mint_config_account: MintConfig,
+    pub lp_mint: Pubkey,
+    pub route: Pubkey,
+    pub route_account: Route,
+}
+
+impl Fixture {
+    pub fn new() -> Self {
+        Self::with_token_program(spl_token::ID)
+    }
+
+    pub fn with_token_program(token_program: Pubkey) -> Self {
+        Self::build(VAULT_ID, token_program)
+    }
+
+    /// 同一程序下的另一个金库
+    pub fn with_vault_id(vault_id: u64) -> Self {
+        Self::build(vault_id, spl_token::ID)
+    }
+
+    fn build(vault_id: u64, token_program: Pubkey) -> Self {
+        let admin = Pubkey::new_unique();
+        let user = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let (config, config_bump) = config_pda(vault_id);
+        let (vault_authority, vault_authority_bump) = vault_authority_pda(&config);
+        let (mint_config, mint_config_bump) = mint_config_pda(&config, &mint);
+        let (lp_mint, _) = lp_mint_pda(&config, &mint);
+        let (route, route_bump) = route_pda(&config, DST_EID);
+        Self {
+            token_program,
+            admin,
+            user,
+            mint,
+            config,
+            vault_authority,
+            vault_token_account: vault_ata(&vault_authority, &mint, &token_program),
+            config_account: Config {
+                admin,
+                allowed_caller_authority: admin,
+                vault_authority_bump,
+                allowed_callers: vec![],
+                pending_admin: Pubkey::default(),
+                guardian: Pubkey::default(),
+                paused: 0,
+                treasury: admin,
+                remote_peers: vec![],
+                next_receipt_id: 0,
+                refund_timeout_secs: 3_600,
+                refund_fee_bps: 0,
+                vault_id,
+                bump: config_bump,
+                layerzero_oapp_program: LAYERZERO_OAPP_PROGRAM_ID,
+                layerzero_endpoint_program: LAYERZERO_ENDPOINT_PROGRAM_ID,
+                lz_store: store_pda(&LAYERZERO_OAPP_PROGRAM_ID),
+                lz_endpoint: endpoint_pda(&LAYERZERO_ENDPOINT_PROGRAM_ID),
+            },
+            mint_config,
+            mint_config_account: MintConfig {
+                mint,
+                enabled: true,
+                decimals: DECIMALS,
+                min_deposit: 0,
+                max_deposit: 0,
+                daily_outflow_cap: 0,
+                outflow_window_start: 0,
+                outflow_in_window: 0,
+                lp_mint,
+                dst_tokens: vec![DstToken { dst_eid: DST_EID, token: DST_TOKEN, decimals: DECIMALS }],
+                bump: mint_config_bump,
+                paused: 0,
+                protocol_fee_bps: 0,
+                lp_fee_bps: 0,
+                refund_dust: false,
+            },
+            lp_mint,
+            route,
+            route_account: Route {
+                dst_eid: DST_EID,
+                enabled: true,
+                min_amount: 0,
+                max_amount: 0,
+                executor: ExecutorConfig { lz_receive_gas: LZ_RECEIVE_GAS, ..Default::default() },
+                tokens: vec![RouteToken { mint, token: DST_TOKEN }],
+                merchants: vec![],
+                bump: route_bump,
+                peer: peer_pda(&LAYERZERO_OAPP_PROGRAM_ID, DST_EID),
+            },
+        }
+    }
+
+    pub fn config_info(&self) -> AccountInfo<'static> {
+        anchor_account_info(self.config, &self.config_account)
+    }
+
+    pub fn mint_config_info(&self) -> AccountInfo<'static> {
+        anchor_account_info(self.mint_config, &self.mint_config_account)
+    }
+
+    /// user 发出的一笔付款回执
+    pub fn receipt(&self, id: u64, status: PaymentStatus) -> PaymentReceipt {
+        PaymentReceipt {
+            id,
+            user: self.user,
+            mint: self.mint,
+            amount: 1_000,
+            net_amount: 990,
+            scaled_amount: 990,
+            dst_eid: DST_EID,
+            dst_token: DST_TOKEN,
+            merchant: [2u8; 32],
+            message_hash: [0u8; 32],
+            status,
+            created_at: 1_000,
+            bump: receipt_pda(&self.config, id).1,
+        }
+    }
+
+    pub fn route_info(&self) -> AccountInfo<'static> {
+        anchor_account_info(self.route, &self.route_account)
+    }
+
+    pub fn mint_info(&self) -> AccountInfo<'static> {
+        program_mint_info(self.token_program, self.mint, None, 1_000_000_000)
+    }
+
+    /// LP mint 与底层 mint 由同一个 token program 创建
+    pub fn lp_mint_info(&self) -> AccountInfo<'static> {
+        program_mint_info(self.token_program, self.lp_mint, Some(self.vault_authority), 1_000)
+    }
+
+    pub fn token_account_info(&self, key: Pubkey, mint: Pubkey, owner: Pubkey, amount: u64) -> AccountInfo<'static> {
+        program_token_account_info(self.token_program, key, mint, owner, amount)
+    }
+
+    /// user 的 LP 份额 ATA
+    pub fn user_lp_info(&self) -> AccountInfo<'static> {
+        let ata = get_associated_token_address_with_program_id(&self.user, &self.lp_mint, &self.token_program);
+        self.token_account_info(ata, self.lp_mint, self.user, 1_000)
+    }
+
+    /// 规范的金库 ATA（owner = vault_authority）
+    pub fn vault_info(&self) -> AccountInfo<'static> {
+        self.token_account_info(self.vault_token_account, self.mint, self.vault_authority, 1_000)
+    }
+
+    /// treasury（= admin）名下的代币账户
+    pub fn treasury_token_info(&self) -> AccountInfo<'static> {
+        self.token_account_info(Pubkey::new_unique(), self.mint, self.config_account.treasury, 0)
+    }
+
+    pub fn user_token_info(&self) -> AccountInfo<'static> {
+        self.token_account_info(Pubkey::new_unique(), self.mint, self.user, 1_000)
+    }
+
+    pub fn token_program_info(&self) -> AccountInfo<'static> {
+        program_info(self.token_program)
+    }
+
+    /// quote_deposit / deposit_from_user 的 LayerZero 账户：OApp 程序、peer、store、endpoint、Endpoint 程序
+    pub fn layerzero_infos(&self) -> Vec<AccountInfo<'static>> {
+        let (oapp_program, endpoint_program) =
+            (self.config_account.layerzero_oapp_program, self.config_account.layerzero_endpoint_program);
+        vec![
+            program_info(oapp_program),
+            unchecked_info(self.route_account.peer),
+            unchecked_info(store_pda(&oapp_program)),
+            unchecked_info(endpoint_pda(&endpoint_program)),
+            program_info(endpoint_program),
+        ]
+    }
+}
+
+/// 非 SBF 目标下 sysvar 默认不可用；init_if_needed 等约束需要 Rent
+struct TestSyscalls;
+
+impl SyscallStubs for TestSyscalls {
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        0
+    }
+}
+
+pub fn install_syscalls() {
+    static ONCE: Once = Once::new();
+    ONCE.call_once(|| {
+        set_syscall_stubs(Box::new(TestSyscalls));
+    });
+}
+
+/// 只执行账户反序列化与约束校验（不执行 handler）
+pub fn try_accounts<T>(infos: Vec<AccountInfo<'static>>, ix_data: &[u8]) -> Result<T>
+where
+    T: Accounts<'static, <T as Bumps>::Bumps> + Bumps,
+    <T as Bumps>::Bumps: Default,
+{
+    try_program_accounts(&transfer_contract::ID, infos, ix_data)
+}
+
+/// 同 try_accounts，用于其它程序（如 mock-oapp）的账户结构
+pub fn try_program_accounts<T>(program_id: &'static Pubkey, infos: Vec<AccountInfo<'static>>, ix_data: &[u8]) -> Result<T>
+where
+    T: Accounts<'static, <T as Bumps>::Bumps> + Bumps,
+    <T as Bumps>::Bumps: Default,
+{
+    accounts_with_remaining(program_id, infos, ix_data).map(|(accounts, _)| accounts)
+}
+
+/// 账户结构之后多出的账户作为 remaining accounts 返回
+fn accounts_with_remaining<T>(
+    program_id: &'static Pubkey,
+    infos: Vec<AccountInfo<'static>>,
+    ix_data: &[u8],
+) -> Result<(T, &'static [AccountInfo<'static>])>
+where
+    T: Accounts<'static, <T as Bumps>::Bumps> + Bumps,
+    <T as Bumps>::Bumps: Default,
+{
+    install_syscalls();
+    let infos: &'static [AccountInfo<'static>] = Box::leak(infos.into_boxed_slice());
+    let mut remaining = infos;
+    let mut bumps = <T as Bumps>::Bumps::default();
+    let mut reallocs = BTreeSet::new();
+    let accounts = T::try_accounts(program_id, &mut remaining, ix_data, &mut bumps, &mut reallocs)?;
+    Ok((accounts, remaining))
+}
+
+/// 账户校验通过后执行 handler（handler 内不能有 CPI）；`args` 为指令参数（不含 discriminator），
+/// 账户结构之外的 infos 作为 remaining accounts
+pub fn run_handler<T, R>(
+    program_id: &'static Pubkey,
+    infos: Vec<AccountInfo<'static>>,
+    args: &[u8],
+    handler: impl FnOnce(Context<'_, '_, 'static, 'static, T>) -> Result<R>,
+) -> Result<R>
+where
+    T: Accounts<'static, <T as Bumps>::Bumps> + Bumps,
+    <T as Bumps>::Bumps: Default,
+{
+    let (mut accounts, remaining) = accounts_with_remaining::<T>(program_id, infos, args)?;
+    handler(Context::new(program_id, &mut accounts, remaining, Default::default()))
+}
+
+/// 同时适用于 anchor 内置错误和本程序的 ErrorCode
+pub fn assert_error<T, E>(result: Result<T>, expected: E)
+where
+    E: Into<u32> + std::fmt::Debug + Copy,
+{
+    match result {
+        Ok(_) => panic!("expected {expected:?}, got Ok"),
+        Err(Error::AnchorError(e)) => assert_eq!(e.error_code_number, expected.into(), "{e}"),
+        Err(e) => panic!("expected {expected:?}, got {e:?}"),
+    }
+}
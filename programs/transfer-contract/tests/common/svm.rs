@@ -0,0 +1,194 @@
+//! 基于 LiteSVM 的测试运行时：加载 transfer_contract 与 mock_oapp 的 SBF 构建产物，
+//! 在真实的 SVM（BPF loader、system program、SPL Token / Token-2022 / ATA 程序）上执行交易。
+//!
+//! 需要先构建 SBF 产物：`cargo test-sbf` 会构建并设置 SBF_OUT_DIR；
+//! 也可以先 `anchor build`，产物默认从工作区的 target/deploy 读取。
+//! 测试使用任意 Pubkey 作为签名方：关闭了签名校验，交易中的签名只占位。
+
+use std::path::PathBuf;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_spl::token::spl_token;
+use anchor_spl::token::spl_token::solana_program::program_pack::Pack;
+use litesvm::types::FailedTransactionMetadata;
+use litesvm::LiteSVM;
+use solana_instruction::error::InstructionError;
+use solana_message::Message;
+use solana_signature::Signature;
+use solana_transaction::Transaction;
+use solana_transaction_error::TransactionError;
+
+pub use solana_account::Account;
+
+/// native loader 拥有的可执行账户不会被当作 BPF 程序加载，用作只需存在的程序账户（如 Endpoint 程序）
+const NATIVE_LOADER_ID: Pubkey = pubkey!("NativeLoader1111111111111111111111111111111");
+
+/// 交易中的一次 CPI；运行时只记录账户地址，不记录签名 / 可写标记
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InnerInstruction {
+    pub program_id: Pubkey,
+    pub accounts: Vec<Pubkey>,
+    pub data: Vec<u8>,
+}
+
+/// 成功执行的交易
+#[derive(Debug)]
+pub struct Outcome {
+    pub logs: Vec<String>,
+    /// 按调用顺序记录的 CPI
+    pub inner_instructions: Vec<InnerInstruction>,
+    pub return_data: Option<(Pubkey, Vec<u8>)>,
+}
+
+impl Outcome {
+    /// 发给 program_id 的 CPI
+    pub fn inner_instructions(&self, program_id: &Pubkey) -> Vec<&InnerInstruction> {
+        self.inner_instructions.iter().filter(|ix| ix.program_id == *program_id).collect()
+    }
+
+    /// 顶层指令通过 return data 返回的值
+    pub fn return_value<T: AnchorDeserialize>(&self, program_id: &Pubkey) -> T {
+        let (program, data) = self.return_data.as_ref().expect("no return data");
+        assert_eq!(program, program_id);
+        T::try_from_slice(data).unwrap()
+    }
+}
+
+/// 失败的交易：账户保持执行前的状态
+#[derive(Debug)]
+pub struct Failure {
+    pub error: TransactionError,
+    pub logs: Vec<String>,
+}
+
+pub type TransactionResult = std::result::Result<Outcome, Failure>;
+
+pub struct Svm {
+    svm: LiteSVM,
+    /// 交易手续费付款方，与被测账户分开，避免影响 lamports 断言
+    payer: Pubkey,
+}
+
+impl Default for Svm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Svm {
+    /// 已加载 system program、SPL 程序以及 transfer_contract 与 mock-oapp 的 SBF 产物
+    pub fn new() -> Self {
+        let mut svm = LiteSVM::new()
+            .with_sigverify(false)
+            .with_blockhash_check(false)
+            // 相同的指令会产生相同的占位签名，关闭历史记录以允许重复提交
+            .with_transaction_history(0);
+        svm.add_program(transfer_contract::ID, &read_program("transfer_contract")).unwrap();
+        svm.add_program(mock_oapp::ID, &read_program("mock_oapp")).unwrap();
+        svm.set_sysvar(&Clock { unix_timestamp: 1_700_000_000, ..Clock::default() });
+        let payer = Pubkey::new_unique();
+        svm.airdrop(&payer, 100 * 1_000_000_000).unwrap();
+        Self { svm, payer }
+    }
+
+    pub fn clock(&self) -> Clock {
+        self.svm.get_sysvar()
+    }
+
+    pub fn set_account(&mut self, key: Pubkey, account: Account) {
+        self.svm.set_account(key, account).unwrap();
+    }
+
+    /// 只需存在、不会被调用的可执行账户
+    pub fn add_stub_program(&mut self, key: Pubkey) {
+        let account = Account { lamports: 1, owner: NATIVE_LOADER_ID, executable: true, ..Account::default() };
+        self.set_account(key, account);
+    }
+
+    /// 把测试 helper 构造的 AccountInfo（见 common）写入账户表；签名 / 可写标记由指令决定
+    pub fn load(&mut self, infos: impl IntoIterator<Item = AccountInfo<'static>>) {
+        for info in infos {
+            let account = Account {
+                lamports: info.lamports(),
+                data: info.data.borrow().to_vec(),
+                owner: *info.owner,
+                executable: info.executable,
+                rent_epoch: 0,
+            };
+            self.set_account(*info.key, account);
+        }
+    }
+
+    pub fn account(&self, key: &Pubkey) -> Option<Account> {
+        self.svm.get_account(key)
+    }
+
+    pub fn lamports(&self, key: &Pubkey) -> u64 {
+        self.account(key).map_or(0, |a| a.lamports)
+    }
+
+    pub fn anchor_account<T: AccountDeserialize>(&self, key: &Pubkey) -> T {
+        let account = self.account(key).unwrap_or_else(|| panic!("account {key} not found"));
+        T::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    /// SPL Token / Token-2022 代币账户余额（基础布局相同）
+    pub fn token_amount(&self, key: &Pubkey) -> u64 {
+        let account = self.account(key).unwrap_or_else(|| panic!("token account {key} not found"));
+        spl_token::state::Account::unpack_from_slice(&account.data[..spl_token::state::Account::LEN]).unwrap().amount
+    }
+
+    /// 执行一笔只含 `ix` 的交易；带 is_signer 的账户视为已签名
+    pub fn process(&mut self, ix: &Instruction) -> TransactionResult {
+        let message = Message::new(std::slice::from_ref(ix), Some(&self.payer));
+        let signatures = vec![Signature::default(); message.header.num_required_signatures as usize];
+        let keys = message.account_keys.clone();
+        match self.svm.send_transaction(Transaction { signatures, message }) {
+            Ok(meta) => {
+                let inner_instructions = meta
+                    .inner_instructions
+                    .iter()
+                    .flatten()
+                    .map(|inner| InnerInstruction {
+                        program_id: keys[inner.instruction.program_id_index as usize],
+                        accounts: inner.instruction.accounts.iter().map(|&i| keys[i as usize]).collect(),
+                        data: inner.instruction.data.clone(),
+                    })
+                    .collect();
+                let return_data = meta.return_data;
+                let return_data = (!return_data.data.is_empty()).then_some((return_data.program_id, return_data.data));
+                Ok(Outcome { logs: meta.logs, inner_instructions, return_data })
+            }
+            Err(FailedTransactionMetadata { err, meta }) => Err(Failure { error: err, logs: meta.logs }),
+        }
+    }
+}
+
+/// SBF 产物所在目录：cargo test-sbf 设置的 SBF_OUT_DIR（或旧的 BPF_OUT_DIR），默认为工作区的 target/deploy
+fn read_program(name: &str) -> Vec<u8> {
+    let dir = std::env::var_os("SBF_OUT_DIR")
+        .or_else(|| std::env::var_os("BPF_OUT_DIR"))
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../target/deploy"));
+    let path = dir.join(format!("{name}.so"));
+    std::fs::read(&path)
+        .unwrap_or_else(|e| panic!("{}: {e}; run `cargo test-sbf` or `anchor build` first", path.display()))
+}
+
+/// 交易失败且错误为 Custom(expected)（Anchor 错误与本程序 ErrorCode 均适用）
+pub fn assert_failure<E>(result: TransactionResult, expected: E)
+where
+    E: Into<u32> + std::fmt::Debug + Copy,
+{
+    match result {
+        Ok(outcome) => panic!("expected {expected:?}, got Ok: {:#?}", outcome.logs),
+        Err(Failure { error: TransactionError::InstructionError(_, InstructionError::Custom(code)), .. })
+            if code == expected.into() => {}
+        Err(failure) => panic!("expected {expected:?}, got {failure:#?}"),
+    }
+}
+
+pub fn assert_success(result: TransactionResult) -> Outcome {
+    result.unwrap_or_else(|failure| panic!("transaction failed: {failure:#?}"))
+}
@@ -0,0 +1,32 @@
+use transfer_contract::state::decimals::scale_amount;
+
+#[test]
+fn same_decimals_is_identity() {
+    assert_eq!(scale_amount(1_234_567, 6, 6), Some((1_234_567, 0)));
+}
+
+#[test]
+fn scales_up_without_dust() {
+    // 6 位 USDC → 18 位代币
+    assert_eq!(scale_amount(1_500_000, 6, 18), Some((1_500_000_000_000_000_000, 0)));
+    // u64::MAX 放大后超出 u64，但仍在 u128 范围内
+    assert_eq!(
+        scale_amount(u64::MAX, 6, 18),
+        Some((u64::MAX as u128 * 1_000_000_000_000, 0))
+    );
+}
+
+#[test]
+fn scales_down_and_reports_dust() {
+    // 9 位 → 6 位：最后 3 位无法表示
+    assert_eq!(scale_amount(1_000_001_234, 9, 6), Some((1_000_001, 234)));
+    // 金额全部是 dust
+    assert_eq!(scale_amount(999, 9, 6), Some((0, 999)));
+}
+
+#[test]
+fn overflow_is_rejected() {
+    assert_eq!(scale_amount(u64::MAX, 0, 39), None);
+    assert_eq!(scale_amount(u64::MAX, 0, 255), None);
+    assert_eq!(scale_amount(1, 255, 0), None);
+}
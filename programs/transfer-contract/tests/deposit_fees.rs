@@ -0,0 +1,77 @@
+mod common;
+
+use common::*;
+use transfer_contract::instructions::deposit_from_user::{check_native_fee_charged, unused_fee};
+use transfer_contract::state::{DepositFees, ErrorCode, MintConfigParams};
+
+fn params(protocol_fee_bps: u16, lp_fee_bps: u16) -> MintConfigParams {
+    MintConfigParams {
+        enabled: true,
+        min_deposit: 0,
+        max_deposit: 0,
+        daily_outflow_cap: 0,
+        dst_tokens: vec![],
+        protocol_fee_bps,
+        lp_fee_bps,
+        refund_dust: false,
+    }
+}
+
+#[test]
+fn fees_are_split_and_round_down() {
+    let mut mint_config = Fixture::new().mint_config_account;
+    mint_config.apply(params(30, 20)).unwrap();
+    assert_eq!(
+        mint_config.deposit_fees(1_000_000).unwrap(),
+        DepositFees { protocol_fee: 3_000, lp_fee: 2_000, net_amount: 995_000 }
+    );
+    // 不足 1 单位的手续费向下取整为 0
+    assert_eq!(
+        mint_config.deposit_fees(99).unwrap(),
+        DepositFees { protocol_fee: 0, lp_fee: 0, net_amount: 99 }
+    );
+}
+
+#[test]
+fn zero_fees_pass_amount_through() {
+    let mint_config = Fixture::new().mint_config_account;
+    assert_eq!(
+        mint_config.deposit_fees(u64::MAX).unwrap(),
+        DepositFees { protocol_fee: 0, lp_fee: 0, net_amount: u64::MAX }
+    );
+}
+
+#[test]
+fn total_fee_is_capped() {
+    let mut mint_config = Fixture::new().mint_config_account;
+    mint_config.apply(params(600, 400)).unwrap();
+    assert_error(mint_config.apply(params(600, 401)), ErrorCode::FeeTooHigh);
+}
+
+#[test]
+fn native_fee_must_be_charged_from_the_store() {
+    // Store 原有 1_000：用户转入的手续费被 OApp 全部扣走后余额回到 1_000
+    check_native_fee_charged(1_000, 1_000).unwrap();
+    // 没有扣走（或只扣了一部分）
+    assert_error(check_native_fee_charged(1_000, 1_500), ErrorCode::LayerZeroFeeNotCharged);
+    assert_error(check_native_fee_charged(1_000, 1_001), ErrorCode::LayerZeroFeeNotCharged);
+    // 多扣了 Store 自己的余额
+    assert_error(check_native_fee_charged(1_000, 999), ErrorCode::LayerZeroFeeExceeded);
+}
+
+#[test]
+fn unused_layerzero_fee_is_refunded() {
+    // 付款方原有 1_000，用户预付 500
+    assert_eq!(unused_fee(500, 1_000, 1_000).unwrap(), 0);
+    assert_eq!(unused_fee(500, 1_000, 1_200).unwrap(), 200);
+    assert_eq!(unused_fee(500, 1_000, 1_500).unwrap(), 500);
+    // 期间有人转入付款方，退款也不超过用户预付的部分
+    assert_eq!(unused_fee(500, 1_000, 9_000).unwrap(), 500);
+    assert_eq!(unused_fee(0, 0, 0).unwrap(), 0);
+}
+
+#[test]
+fn layerzero_fee_above_payment_is_rejected() {
+    // OApp 从付款方多扣了 1，即金库代付
+    assert_error(unused_fee(500, 1_000, 999), ErrorCode::LayerZeroFeeExceeded);
+}
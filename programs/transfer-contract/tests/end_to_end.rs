@@ -0,0 +1,213 @@
+//! 在 LiteSVM（common::svm）上端到端执行 transfer_contract 与 mock-oapp 的 SBF 产物：
+//! quote_deposit / deposit_from_user 通过 CPI 调用桩程序的 quote_send / relay_send，
+//! 桩程序的 deliver 以 Store PDA 签名回调 lz_receive_ack（lz_receive 见 tests/lz_receive.rs）。
+//! 发往桩程序的内容从交易记录的 relay_send CPI 指令中解析。通过 cargo test-sbf 运行。
+
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use anchor_lang::error::ErrorCode as AnchorErrorCode;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022;
+
+use common::e2e::*;
+use common::svm::{assert_failure, assert_success, Outcome};
+use common::*;
+use transfer_contract::instructions::deposit_from_user::{
+    DepositParams, MessagingFee, QuoteSendParams, RelaySendParams, QUOTE_SEND_DISCRIMINATOR, RELAY_SEND_DISCRIMINATOR,
+};
+use transfer_contract::messaging::{Ack, Message, Payout};
+use transfer_contract::state::{ErrorCode, PaymentReceipt, PaymentStatus};
+
+/// deposit_from_user 发给桩程序的 relay_send：(账户, 参数, 解码后的消息)
+fn relay_send(outcome: &Outcome) -> (Vec<Pubkey>, RelaySendParams, Vec<u8>) {
+    let ix = outcome
+        .inner_instructions(&mock_oapp::ID)
+        .into_iter()
+        .find(|ix| ix.data.starts_with(&RELAY_SEND_DISCRIMINATOR))
+        .expect("relay_send not invoked");
+    let params = RelaySendParams::try_from_slice(&ix.data[8..]).unwrap();
+    let message = mock_oapp::decode_message(&params.message).unwrap();
+    (ix.accounts.clone(), params, message)
+}
+
+#[test]
+fn quote_deposit_returns_oapp_quote() {
+    let mut env = Env::new(spl_token::ID);
+    let outcome = assert_success(env.quote_deposit(env.params(100_000)));
+    let fee: MessagingFee = outcome.return_value(&transfer_contract::ID);
+    assert_eq!(fee, MessagingFee { native_fee: NATIVE_FEE, lz_token_fee: 0 });
+
+    // 用 LZ token 支付时桩程序同时报 LZ token 手续费
+    let params = DepositParams { lz_token_fee: Some(0), ..env.params(100_000) };
+    let outcome = assert_success(env.quote_deposit(params));
+    let fee: MessagingFee = outcome.return_value(&transfer_contract::ID);
+    assert_eq!(fee, MessagingFee { native_fee: NATIVE_FEE, lz_token_fee: LZ_TOKEN_FEE });
+
+    let params = DepositParams { dst_token: evm(9), ..env.params(100_000) };
+    assert_failure(env.quote_deposit(params), ErrorCode::DstTokenNotAllowed);
+}
+
+#[test]
+fn quote_send_carries_peer_receiver_and_send_accounts() {
+    let mut env = Env::new(spl_token::ID);
+    let send_accounts: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+    let mut ix = env.quote_deposit_ix(env.params(100_000));
+    ix.accounts.extend(send_accounts.iter().map(|key| AccountMeta::new_readonly(*key, false)));
+    let outcome = assert_success(env.svm.process(&ix));
+
+    let quote_send = outcome
+        .inner_instructions(&mock_oapp::ID)
+        .into_iter()
+        .find(|ix| ix.data.starts_with(&QUOTE_SEND_DISCRIMINATOR))
+        .expect("quote_send not invoked");
+    let params = QuoteSendParams::try_from_slice(&quote_send.data[8..]).unwrap();
+    assert_eq!((params.dst_eid, params.receiver), (DST_EID, REMOTE_OAPP));
+    // store、peer、endpoint 之后原样跟着 remaining accounts
+    let store = env.deposit_accounts().store;
+    assert_eq!(quote_send.accounts[..2], [store, env.f.route_account.peer]);
+    assert_eq!(quote_send.accounts[3..], send_accounts[..]);
+}
+
+#[test]
+fn deposit_sends_payout_and_charges_the_quoted_fee() {
+    for token_program in [spl_token::ID, spl_token_2022::ID] {
+        let mut env = Env::new(token_program);
+        let f = &env.f;
+        let (user, vault_authority, treasury_token) = (f.user, f.vault_authority, env.treasury_token);
+        let store = env.deposit_accounts().store;
+        let (user_lamports, store_lamports) = (env.svm.lamports(&user), env.svm.lamports(&store));
+        let receiver_lamports = env.svm.lamports(&env.fee_receiver);
+
+        // 手续费上限高于报价：只按报价收取
+        let params = DepositParams { native_fee: Some(2 * NATIVE_FEE), ..env.params(100_000) };
+        let outcome = assert_success(env.deposit(params));
+
+        let expected = Message::Payout(Payout {
+            receipt_id: 0,
+            token: env.dst_token,
+            recipient: env.merchant,
+            amount: 99_000,
+        })
+        .encode();
+        let (accounts, sent, message) = relay_send(&outcome);
+        assert_eq!(message, expected);
+        assert_eq!(accounts[3], vault_authority);
+        assert_eq!(sent.dst_eid, DST_EID);
+        assert_eq!(sent.options, env.f.route_account.executor.options(&env.merchant));
+        assert_eq!((sent.native_fee, sent.lz_token_fee), (NATIVE_FEE, 0));
+
+        // 用户支付回执账户的租金与报价；手续费经 Store 被桩程序转给收费账户，Store 余额不变
+        let receipt_rent = Rent::default().minimum_balance(PaymentReceipt::SPACE);
+        assert_eq!(env.svm.lamports(&user), user_lamports - receipt_rent - NATIVE_FEE);
+        assert_eq!(env.svm.lamports(&env.fee_receiver), receiver_lamports + NATIVE_FEE);
+        assert_eq!(env.svm.lamports(&store), store_lamports);
+        assert_eq!(env.svm.lamports(&vault_authority), 0);
+
+        assert_eq!(env.svm.token_amount(&env.user_token), USER_BALANCE - 100_000);
+        assert_eq!(env.svm.token_amount(&env.f.vault_token_account), VAULT_BALANCE + 99_000);
+        assert_eq!(env.svm.token_amount(&treasury_token), 1_000);
+
+        let receipt: PaymentReceipt = env.svm.anchor_account(&receipt_pda(&env.f.config, 0).0);
+        assert_eq!((receipt.id, receipt.user, receipt.amount), (0, user, 100_000));
+        assert_eq!((receipt.net_amount, receipt.scaled_amount), (99_000, 99_000));
+        assert_eq!((receipt.dst_token, receipt.merchant), (env.dst_token, env.merchant));
+        assert_eq!(receipt.status, PaymentStatus::Sent);
+        assert_eq!(receipt.created_at, env.svm.clock().unix_timestamp);
+        let config: transfer_contract::state::Config = env.svm.anchor_account(&env.f.config);
+        assert_eq!(config.next_receipt_id, 1);
+    }
+}
+
+#[test]
+fn deposit_forwards_remaining_accounts_to_relay_send() {
+    let mut env = Env::new(spl_token::ID);
+    // 客户端追加的 endpoint::send 账户：发送库及其配置（只读）、nonce（可写，桩程序把手续费转到这里）
+    let send_accounts: Vec<AccountMeta> = (0..4)
+        .map(|_| AccountMeta::new_readonly(Pubkey::new_unique(), false))
+        .chain([AccountMeta::new(env.fee_receiver, false)])
+        .collect();
+    let mut ix = Instruction {
+        program_id: transfer_contract::ID,
+        accounts: env.deposit_accounts().to_account_metas(None),
+        data: transfer_contract::instruction::DepositFromUser { params: env.params(100_000) }.data(),
+    };
+    ix.accounts.extend(send_accounts.iter().cloned());
+    let outcome = assert_success(env.svm.process(&ix));
+
+    // relay_send 的具名账户（peer、store、endpoint、caller）之后原样跟着这些账户
+    let (accounts, _, _) = relay_send(&outcome);
+    let send_keys: Vec<Pubkey> = send_accounts.iter().map(|meta| meta.pubkey).collect();
+    assert_eq!(accounts[4..], send_keys[..]);
+}
+
+#[test]
+fn deposit_fails_when_the_oapp_does_not_charge_the_fee() {
+    let mut env = Env::new(spl_token::ID);
+    let user_lamports = env.svm.lamports(&env.f.user);
+    // 没有可写的 remaining account 时桩程序不扣费，手续费会留在 Store
+    let ix = Instruction {
+        program_id: transfer_contract::ID,
+        accounts: env.deposit_accounts().to_account_metas(None),
+        data: transfer_contract::instruction::DepositFromUser { params: env.params(100_000) }.data(),
+    };
+    assert_failure(env.svm.process(&ix), ErrorCode::LayerZeroFeeNotCharged);
+    assert_eq!(env.svm.lamports(&env.f.user), user_lamports);
+}
+
+#[test]
+fn deposit_failures_leave_state_untouched() {
+    let mut env = Env::new(spl_token::ID);
+    let user = env.f.user;
+    let user_lamports = env.svm.lamports(&user);
+
+    let params = DepositParams { native_fee: Some(NATIVE_FEE - 1), ..env.params(100_000) };
+    assert_failure(env.deposit(params), ErrorCode::FeeBelowQuote);
+
+    let accounts =
+        transfer_contract::accounts::DepositFromUser { peer: Pubkey::new_unique(), ..env.deposit_accounts() };
+    assert_failure(env.deposit_with(accounts, env.params(100_000)), ErrorCode::InvalidPeer);
+
+    assert_failure(env.deposit(env.params(USER_BALANCE + 1)), spl_token::error::TokenError::InsufficientFunds as u32);
+
+    // 回执账户的创建与转账都随交易一起回滚
+    assert_eq!(env.svm.lamports(&user), user_lamports);
+    assert_eq!(env.svm.token_amount(&env.user_token), USER_BALANCE);
+    assert!(env.svm.account(&receipt_pda(&env.f.config, 0).0).is_none_or(|a| a.lamports == 0));
+    assert_success(env.deposit(env.params(100_000)));
+}
+
+#[test]
+fn lz_receive_ack_settles_receipt() {
+    let mut env = Env::new(spl_token::ID);
+    assert_success(env.deposit(env.params(100_000)));
+    let receipt = receipt_pda(&env.f.config, 0).0;
+
+    let wrong_id = inbound(1, Message::Ack(Ack { receipt_id: 1, success: true }));
+    assert_failure(env.deliver(env.lz_receive_ack_ix(receipt, wrong_id)), ErrorCode::PayloadMismatch);
+
+    let ack = inbound(1, Message::Ack(Ack { receipt_id: 0, success: false }));
+    assert_success(env.deliver(env.lz_receive_ack_ix(receipt, ack)));
+    assert_eq!(env.svm.anchor_account::<PaymentReceipt>(&receipt).status, PaymentStatus::Failed);
+
+    // 已结算的付款不能再被 ack
+    let again = inbound(2, Message::Ack(Ack { receipt_id: 0, success: true }));
+    assert_failure(env.deliver(env.lz_receive_ack_ix(receipt, again)), ErrorCode::PaymentNotPending);
+}
+
+#[test]
+fn lz_receive_ack_rejects_receipt_of_another_vault() {
+    let mut env = Env::new(spl_token::ID);
+    // 同一程序下另一个金库的回执：编号相同，但 seeds 中的 config 不同
+    let other = Fixture::with_vault_id(VAULT_ID + 1);
+    let receipt = receipt_pda(&other.config, 0).0;
+    env.svm.load([anchor_account_info(receipt, &other.receipt(0, PaymentStatus::Sent))]);
+
+    let ack = inbound(1, Message::Ack(Ack { receipt_id: 0, success: false }));
+    assert_failure(env.deliver(env.lz_receive_ack_ix(receipt, ack)), AnchorErrorCode::ConstraintSeeds);
+    assert_eq!(env.svm.anchor_account::<PaymentReceipt>(&receipt).status, PaymentStatus::Sent);
+}
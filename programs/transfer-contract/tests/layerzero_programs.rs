@@ -0,0 +1,215 @@
+//! LayerZero OApp / Endpoint 程序 ID 存在 Config 中：
+//! quote_deposit / deposit_from_user 只接受 Config 里记录的程序，set_layerzero_programs 由 admin 切换。
+//! peer / store / endpoint 由本程序按 seeds 推导：store / endpoint 由 set_layerzero_programs 缓存在 Config 中，
+//! peer 缓存在路由中，由 set_route 写入，切换 OApp 程序后由 refresh_route_peer 重新推导。
+
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use common::*;
+use transfer_contract::instructions::deposit_from_user::{
+    endpoint_pda, peer_pda, store_pda, DepositParams, LAYERZERO_ENDPOINT_PROGRAM_ID, LAYERZERO_OAPP_PROGRAM_ID,
+};
+use transfer_contract::state::{Config, ErrorCode, Route, RouteParams};
+use transfer_contract::{QuoteDeposit, RefreshRoutePeer, SetLayerzeroPrograms, SetRoute};
+
+/// Fixture::layerzero_infos 中各账户的位置
+const OAPP_PROGRAM: usize = 0;
+const PEER: usize = 1;
+const STORE: usize = 2;
+const ENDPOINT: usize = 3;
+const ENDPOINT_PROGRAM: usize = 4;
+
+fn quote_deposit(f: &Fixture, oapp_program: Pubkey, endpoint_program: Pubkey) -> Result<QuoteDeposit<'static>> {
+    let mut layerzero = f.layerzero_infos();
+    layerzero[OAPP_PROGRAM] = program_info(oapp_program);
+    layerzero[ENDPOINT_PROGRAM] = program_info(endpoint_program);
+    quote_deposit_with(f, layerzero)
+}
+
+fn quote_deposit_with(f: &Fixture, layerzero: Vec<AccountInfo<'static>>) -> Result<QuoteDeposit<'static>> {
+    let params = DepositParams {
+        amount: 100,
+        dst_eid: DST_EID,
+        dst_token: DST_TOKEN,
+        merchant: [2u8; 32],
+        options: None,
+        native_fee: None,
+        lz_token_fee: None,
+    };
+    let mut infos = vec![f.config_info(), f.mint_info(), f.mint_config_info(), f.route_info()];
+    infos.extend(layerzero);
+    try_accounts(infos, &params.try_to_vec().unwrap())
+}
+
+/// 执行 set_layerzero_programs，成功时返回更新后的 Config
+fn set_layerzero_programs(
+    f: &Fixture,
+    signer: Pubkey,
+    oapp_program: Pubkey,
+    endpoint_program: Pubkey,
+) -> Result<Config> {
+    let args = (oapp_program, endpoint_program).try_to_vec().unwrap();
+    run_handler(&transfer_contract::ID, vec![f.config_info(), signer_info(signer)], &args, |ctx| {
+        let Context { program_id, accounts, remaining_accounts, bumps, .. } = ctx;
+        let ctx = Context::<SetLayerzeroPrograms>::new(program_id, &mut *accounts, remaining_accounts, bumps);
+        transfer_contract::transfer_contract::set_layerzero_programs(ctx, oapp_program, endpoint_program)?;
+        Ok((*accounts.config).clone())
+    })
+}
+
+/// 对已存在的路由执行 set_route（不需要创建账户），返回更新后的路由
+fn set_route(f: &Fixture) -> Result<Route> {
+    let params = RouteParams {
+        enabled: true,
+        min_amount: 0,
+        max_amount: 0,
+        executor: f.route_account.executor,
+        tokens: f.route_account.tokens.clone(),
+        merchants: vec![],
+    };
+    let args = (DST_EID, params.clone()).try_to_vec().unwrap();
+    // init_if_needed 要求已存在的账户大小等于 Route::SPACE
+    let mut data = Vec::new();
+    f.route_account.try_serialize(&mut data).unwrap();
+    data.resize(Route::SPACE, 0);
+    let route = account_info(f.route, transfer_contract::ID, data, false, true, false);
+    let infos = vec![f.config_info(), route, signer_info(f.admin), program_info(system_program::ID)];
+    run_handler(&transfer_contract::ID, infos, &args, |ctx| {
+        let Context { program_id, accounts, remaining_accounts, bumps, .. } = ctx;
+        let ctx = Context::<SetRoute>::new(program_id, &mut *accounts, remaining_accounts, bumps);
+        transfer_contract::transfer_contract::set_route(ctx, DST_EID, params)?;
+        Ok((*accounts.route).clone())
+    })
+}
+
+/// 执行 refresh_route_peer，成功时返回更新后的路由
+fn refresh_route_peer(f: &Fixture, signer: Pubkey) -> Result<Route> {
+    let infos = vec![f.config_info(), f.route_info(), signer_info(signer)];
+    run_handler(&transfer_contract::ID, infos, &[], |ctx| {
+        let Context { program_id, accounts, remaining_accounts, bumps, .. } = ctx;
+        let ctx = Context::<RefreshRoutePeer>::new(program_id, &mut *accounts, remaining_accounts, bumps);
+        transfer_contract::transfer_contract::refresh_route_peer(ctx)?;
+        Ok((*accounts.route).clone())
+    })
+}
+
+#[test]
+fn quote_deposit_accepts_configured_programs() {
+    let f = Fixture::new();
+    assert_eq!(f.config_account.layerzero_oapp_program, LAYERZERO_OAPP_PROGRAM_ID);
+    assert_eq!(f.config_account.layerzero_endpoint_program, LAYERZERO_ENDPOINT_PROGRAM_ID);
+    if let Err(e) = quote_deposit(&f, LAYERZERO_OAPP_PROGRAM_ID, LAYERZERO_ENDPOINT_PROGRAM_ID) {
+        panic!("quote_deposit rejected: {e:?}");
+    }
+}
+
+#[test]
+fn quote_deposit_rejects_other_programs() {
+    let f = Fixture::new();
+    assert_error(
+        quote_deposit(&f, Pubkey::new_unique(), LAYERZERO_ENDPOINT_PROGRAM_ID),
+        ErrorCode::InvalidOAppProgram,
+    );
+    assert_error(
+        quote_deposit(&f, LAYERZERO_OAPP_PROGRAM_ID, Pubkey::new_unique()),
+        ErrorCode::InvalidEndpointProgram,
+    );
+}
+
+#[test]
+fn switching_to_mock_oapp_takes_effect() {
+    let mut f = Fixture::new();
+    let endpoint_program = Pubkey::new_unique();
+    f.config_account = set_layerzero_programs(&f, f.admin, mock_oapp::ID, endpoint_program).unwrap();
+    let config = &f.config_account;
+    assert_eq!((config.layerzero_oapp_program, config.layerzero_endpoint_program), (mock_oapp::ID, endpoint_program));
+    // Store / Endpoint PDA 在切换时推导一次，之后入金与报价直接比较地址
+    assert_eq!((config.lz_store, config.lz_endpoint), (store_pda(&mock_oapp::ID), endpoint_pda(&endpoint_program)));
+
+    // 路由仍缓存着旧 OApp 的 peer，重新 set_route 前按新 OApp 推导的 peer 被拒绝
+    let mut layerzero = f.layerzero_infos();
+    layerzero[PEER] = unchecked_info(peer_pda(&mock_oapp::ID, DST_EID));
+    assert_error(quote_deposit_with(&f, layerzero), ErrorCode::InvalidPeer);
+    f.route_account = refresh_route_peer(&f, f.admin).unwrap();
+    assert_eq!(f.route_account.peer, peer_pda(&mock_oapp::ID, DST_EID));
+    if let Err(e) = quote_deposit(&f, mock_oapp::ID, endpoint_program) {
+        panic!("quote_deposit rejected mock-oapp: {e:?}");
+    }
+    // 原来的程序及其 Store / Endpoint 不再被接受
+    assert_error(quote_deposit(&f, LAYERZERO_OAPP_PROGRAM_ID, endpoint_program), ErrorCode::InvalidOAppProgram);
+    let mut layerzero = f.layerzero_infos();
+    layerzero[STORE] = unchecked_info(store_pda(&LAYERZERO_OAPP_PROGRAM_ID));
+    assert_error(quote_deposit_with(&f, layerzero), ErrorCode::InvalidStore);
+    let mut layerzero = f.layerzero_infos();
+    layerzero[ENDPOINT] = unchecked_info(endpoint_pda(&LAYERZERO_ENDPOINT_PROGRAM_ID));
+    assert_error(quote_deposit_with(&f, layerzero), ErrorCode::InvalidEndpoint);
+}
+
+#[test]
+fn set_layerzero_programs_is_admin_only() {
+    let f = Fixture::new();
+    assert_error(
+        set_layerzero_programs(&f, f.user, mock_oapp::ID, LAYERZERO_ENDPOINT_PROGRAM_ID),
+        ErrorCode::NotAuthorized,
+    );
+}
+
+#[test]
+fn set_layerzero_programs_rejects_default_keys() {
+    let f = Fixture::new();
+    assert_error(
+        set_layerzero_programs(&f, f.admin, Pubkey::default(), LAYERZERO_ENDPOINT_PROGRAM_ID),
+        ErrorCode::InvalidOAppProgram,
+    );
+    assert_error(
+        set_layerzero_programs(&f, f.admin, mock_oapp::ID, Pubkey::default()),
+        ErrorCode::InvalidEndpointProgram,
+    );
+}
+
+#[test]
+fn layerzero_pdas_are_derived_from_config() {
+    let f = Fixture::new();
+    let wrong = |index: usize, key: Pubkey| {
+        let mut layerzero = f.layerzero_infos();
+        layerzero[index] = unchecked_info(key);
+        quote_deposit_with(&f, layerzero)
+    };
+    assert_error(wrong(PEER, Pubkey::new_unique()), ErrorCode::InvalidPeer);
+    // 其它 dst_eid 的 Peer
+    assert_error(wrong(PEER, peer_pda(&LAYERZERO_OAPP_PROGRAM_ID, DST_EID + 1)), ErrorCode::InvalidPeer);
+    assert_error(wrong(STORE, Pubkey::new_unique()), ErrorCode::InvalidStore);
+    // 其它 OApp 程序的 Store
+    assert_error(wrong(STORE, store_pda(&mock_oapp::ID)), ErrorCode::InvalidStore);
+    assert_error(wrong(ENDPOINT, Pubkey::new_unique()), ErrorCode::InvalidEndpoint);
+    assert_error(wrong(ENDPOINT, endpoint_pda(&LAYERZERO_OAPP_PROGRAM_ID)), ErrorCode::InvalidEndpoint);
+}
+
+#[test]
+fn set_route_caches_the_peer() {
+    let mut f = Fixture::new();
+    f.route_account.peer = Pubkey::default();
+    assert_eq!(set_route(&f).unwrap().peer, peer_pda(&LAYERZERO_OAPP_PROGRAM_ID, DST_EID));
+}
+
+#[test]
+fn refresh_route_peer_only_rederives_the_peer() {
+    let mut f = Fixture::new();
+    f.config_account = set_layerzero_programs(&f, f.admin, mock_oapp::ID, LAYERZERO_ENDPOINT_PROGRAM_ID).unwrap();
+    let before = f.route_account.clone();
+    let route = refresh_route_peer(&f, f.admin).unwrap();
+    assert_eq!(route.peer, peer_pda(&mock_oapp::ID, DST_EID));
+    // 其余路由参数保持不变
+    assert_eq!((route.dst_eid, route.enabled, route.bump), (before.dst_eid, before.enabled, before.bump));
+    assert_eq!((route.min_amount, route.max_amount), (before.min_amount, before.max_amount));
+    assert_eq!((route.tokens, route.merchants), (before.tokens, before.merchants));
+}
+
+#[test]
+fn refresh_route_peer_is_admin_only() {
+    let f = Fixture::new();
+    assert_error(refresh_route_peer(&f, f.user), ErrorCode::NotAuthorized);
+}
@@ -0,0 +1,45 @@
+use transfer_contract::state::shares::{assets_for_shares, shares_for_deposit};
+
+#[test]
+fn first_deposit_mints_thousand_shares_per_unit() {
+    assert_eq!(shares_for_deposit(1_000_000, 0, 0), Some(1_000_000_000));
+}
+
+#[test]
+fn deposit_is_priced_against_vault_assets() {
+    // 金库因手续费增厚到 2_000_000，份额价格翻倍
+    assert_eq!(shares_for_deposit(1_000_000, 2_000_000, 1_000_000_000), Some(500_000_249));
+    // transfer_out 打款后金库只剩一半，份额价格减半
+    assert_eq!(shares_for_deposit(1_000_000, 500_000, 1_000_000_000), Some(1_999_998_000));
+}
+
+#[test]
+fn withdraw_is_pro_rata_and_rounds_down() {
+    assert_eq!(assets_for_shares(250_000_000, 1_000_000, 1_000_000_000), Some(250_000));
+    assert_eq!(assets_for_shares(500_000_000, 500_000, 1_000_000_000), Some(250_000));
+    assert_eq!(assets_for_shares(999, 1, 1_000), Some(0));
+}
+
+#[test]
+fn donation_attack_is_unprofitable() {
+    // 攻击者存入 1 单位后直接向金库转入 1_000_000，抬高份额价格
+    let donation = 1_000_000;
+    let attacker_shares = shares_for_deposit(1, 0, 0).unwrap();
+    let assets = 1 + donation;
+    let deposit = 2_000_000;
+    let victim_shares = shares_for_deposit(deposit, assets, attacker_shares).unwrap();
+    assert!(victim_shares > 0);
+
+    let supply = attacker_shares + victim_shares;
+    let assets = assets + deposit;
+    let attacker_assets = assets_for_shares(attacker_shares, assets, supply).unwrap();
+    assert!(attacker_assets < 1 + donation, "attacker redeemed {attacker_assets}");
+    // 受害者的取整损失不超过存入金额的 0.1%
+    let victim_assets = assets_for_shares(victim_shares, assets, supply).unwrap();
+    assert!(deposit - victim_assets <= deposit / 1_000, "victim redeemed {victim_assets}");
+}
+
+#[test]
+fn overflowing_results_are_rejected() {
+    assert_eq!(shares_for_deposit(u64::MAX, 0, u64::MAX), None);
+}
@@ -0,0 +1,111 @@
+//! lz_receive 的指令级校验（在 LiteSVM 上经桩程序的 deliver 投递）：只接受已登记的源链 OApp、
+//! 消息中的 mint / recipient 必须与账户一致、同一 (src_eid, sender, nonce) 只处理一次。
+//! 去重账户由 `init` 创建，需要真实的 system program，因此不在 common::run_handler 上测试。
+
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use anchor_lang::error::ErrorCode as AnchorErrorCode;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token::spl_token;
+
+use common::e2e::*;
+use common::svm::{assert_failure, assert_success};
+use common::DST_EID;
+use transfer_contract::instructions::lz_receive::LzReceiveParams;
+use transfer_contract::messaging::{Ack, Message, Payout};
+use transfer_contract::state::ErrorCode;
+
+/// SystemError::AccountAlreadyInUse
+const ACCOUNT_ALREADY_IN_USE: u32 = 0;
+
+fn payout(env: &Env, nonce: u64, recipient: Pubkey) -> LzReceiveParams {
+    let message = Message::Payout(Payout {
+        receipt_id: 7,
+        token: env.f.mint.to_bytes(),
+        recipient: recipient.to_bytes(),
+        amount: 500,
+    });
+    inbound(nonce, message)
+}
+
+fn recipient_ata(env: &Env, recipient: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(recipient, &env.f.mint, &env.f.token_program)
+}
+
+#[test]
+fn payout_is_paid_once_per_nonce() {
+    let mut env = Env::new(spl_token::ID);
+    let recipient = env.recipient();
+    let ata = recipient_ata(&env, &recipient);
+
+    assert_success(env.deliver(env.lz_receive_ix(recipient, payout(&env, 1, recipient))));
+    assert!(env.svm.account(&env.received_pda(&payout(&env, 1, recipient))).is_some());
+    assert_eq!(env.svm.token_amount(&ata), 500);
+    assert_eq!(env.svm.token_amount(&env.f.vault_token_account), VAULT_BALANCE - 500);
+
+    // 同一 nonce 重放：去重账户已存在
+    assert_failure(env.deliver(env.lz_receive_ix(recipient, payout(&env, 1, recipient))), ACCOUNT_ALREADY_IN_USE);
+    assert_eq!(env.svm.token_amount(&ata), 500);
+    // 新的 nonce 正常处理
+    assert_success(env.deliver(env.lz_receive_ix(recipient, payout(&env, 2, recipient))));
+    assert_eq!(env.svm.token_amount(&ata), 1_000);
+}
+
+#[test]
+fn lz_receive_requires_the_oapp_store_signature() {
+    let mut env = Env::new(spl_token::ID);
+    let recipient = env.recipient();
+    // 不经 OApp 直接调用时 Store 没有签名
+    let mut ix = env.lz_receive_ix(recipient, payout(&env, 1, recipient));
+    ix.accounts[2].is_signer = false;
+    assert_failure(env.svm.process(&ix), AnchorErrorCode::AccountNotSigner);
+}
+
+#[test]
+fn untrusted_peer_is_rejected() {
+    let mut env = Env::new(spl_token::ID);
+    let recipient = env.recipient();
+    // 未登记的发送方
+    let params = LzReceiveParams { sender: [0x0b; 32], ..payout(&env, 1, recipient) };
+    assert_failure(env.deliver(env.lz_receive_ix(recipient, params)), ErrorCode::UntrustedPeer);
+    // 已登记的发送方，但来自其它源链
+    let params = LzReceiveParams { src_eid: DST_EID + 1, ..payout(&env, 1, recipient) };
+    assert_failure(env.deliver(env.lz_receive_ix(recipient, params.clone())), ErrorCode::UntrustedPeer);
+    // 失败的消息不留下去重记录
+    assert!(env.svm.account(&env.received_pda(&params)).is_none());
+    assert_eq!(env.svm.token_amount(&recipient_ata(&env, &recipient)), 0);
+}
+
+#[test]
+fn payload_must_match_the_accounts() {
+    let mut env = Env::new(spl_token::ID);
+    let recipient = env.recipient();
+    let other = env.recipient();
+    let message = |token: Pubkey, recipient: Pubkey, amount: u128| {
+        let payout = Payout { receipt_id: 7, token: token.to_bytes(), recipient: recipient.to_bytes(), amount };
+        inbound(1, Message::Payout(payout))
+    };
+    let mint = env.f.mint;
+
+    // 消息中的收款人与传入的 recipient 账户不一致
+    let ix = env.lz_receive_ix(other, message(mint, recipient, 500));
+    assert_failure(env.deliver(ix), ErrorCode::PayloadMismatch);
+    // 消息中的 mint 不是传入的 mint
+    let ix = env.lz_receive_ix(recipient, message(Pubkey::new_unique(), recipient, 500));
+    assert_failure(env.deliver(ix), ErrorCode::PayloadMismatch);
+    // 金额为 0 或超出 u64
+    let ix = env.lz_receive_ix(recipient, message(mint, recipient, 0));
+    assert_failure(env.deliver(ix), ErrorCode::InvalidPayload);
+    let ix = env.lz_receive_ix(recipient, message(mint, recipient, u64::MAX as u128 + 1));
+    assert_failure(env.deliver(ix), ErrorCode::InvalidPayload);
+    // ack 应由 lz_receive_ack 处理
+    let ix = env.lz_receive_ix(recipient, inbound(1, Message::Ack(Ack { receipt_id: 0, success: true })));
+    assert_failure(env.deliver(ix), ErrorCode::InvalidPayload);
+
+    assert_eq!(env.svm.token_amount(&recipient_ata(&env, &recipient)), 0);
+    assert_eq!(env.svm.token_amount(&recipient_ata(&env, &other)), 0);
+    assert_eq!(env.svm.token_amount(&env.f.vault_token_account), VAULT_BALANCE);
+}
@@ -0,0 +1,113 @@
+//! 向量来自 Solidity：
+//! abi.encode(uint8(1), uint8(tag), ...fields)
+
+mod common;
+
+use common::*;
+use transfer_contract::messaging::{evm_address, evm_word, Ack, AdminSync, Message, Payout, Refund};
+use transfer_contract::state::ErrorCode;
+
+const USDC: &str = "a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+const MERCHANT: &str = "d8da6bf26964af9d7eed9e03e53415d37aa96045";
+
+fn address(s: &str) -> [u8; 32] {
+    evm_word(&hex::decode(s).unwrap().try_into().unwrap())
+}
+
+/// abi.encode(uint8(1), uint8(101), uint64(42), address(USDC), address(MERCHANT), uint256(1234567))
+const PAYOUT_VECTOR: &str = concat!(
+    "0000000000000000000000000000000000000000000000000000000000000001",
+    "0000000000000000000000000000000000000000000000000000000000000065",
+    "000000000000000000000000000000000000000000000000000000000000002a",
+    "000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+    "000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045",
+    "000000000000000000000000000000000000000000000000000000000012d687",
+);
+
+/// abi.encode(uint8(1), uint8(102), uint64(7), uint256(500000))
+const REFUND_VECTOR: &str = concat!(
+    "0000000000000000000000000000000000000000000000000000000000000001",
+    "0000000000000000000000000000000000000000000000000000000000000066",
+    "0000000000000000000000000000000000000000000000000000000000000007",
+    "000000000000000000000000000000000000000000000000000000000007a120",
+);
+
+/// abi.encode(uint8(1), uint8(103), uint64(42), false)
+const ACK_VECTOR: &str = concat!(
+    "0000000000000000000000000000000000000000000000000000000000000001",
+    "0000000000000000000000000000000000000000000000000000000000000067",
+    "000000000000000000000000000000000000000000000000000000000000002a",
+    "0000000000000000000000000000000000000000000000000000000000000000",
+);
+
+fn assert_round_trip(message: Message, vector: &str) {
+    let bytes = hex::decode(vector).unwrap();
+    assert_eq!(hex::encode(message.encode()), vector);
+    assert_eq!(Message::decode(&bytes).unwrap(), message);
+}
+
+#[test]
+fn payout_matches_solidity() {
+    let payout = Payout { receipt_id: 42, token: address(USDC), recipient: address(MERCHANT), amount: 1_234_567 };
+    assert_round_trip(Message::Payout(payout), PAYOUT_VECTOR);
+}
+
+#[test]
+fn refund_matches_solidity() {
+    assert_round_trip(Message::Refund(Refund { receipt_id: 7, amount: 500_000 }), REFUND_VECTOR);
+}
+
+#[test]
+fn ack_matches_solidity() {
+    assert_round_trip(Message::Ack(Ack { receipt_id: 42, success: false }), ACK_VECTOR);
+}
+
+#[test]
+fn admin_sync_round_trips() {
+    let message = Message::AdminSync(AdminSync { mint: [9u8; 32], enabled: true, paused: 3 });
+    assert_eq!(Message::decode(&message.encode()).unwrap(), message);
+}
+
+#[test]
+fn rejects_malformed_messages() {
+    let good = hex::decode(PAYOUT_VECTOR).unwrap();
+
+    // 长度不足 / 不是 32 字节的整数倍 / 字段数与 tag 不符
+    assert_error(Message::decode(&good[..32]), ErrorCode::InvalidPayload);
+    assert_error(Message::decode(&good[..good.len() - 1]), ErrorCode::InvalidPayload);
+    assert_error(Message::decode(&good[..128]), ErrorCode::InvalidPayload);
+
+    let mut bad = good.clone();
+    bad[31] = 2;
+    assert_error(Message::decode(&bad), ErrorCode::UnsupportedMessageVersion);
+
+    let mut bad = good.clone();
+    bad[63] = 200;
+    assert_error(Message::decode(&bad), ErrorCode::InvalidPayload);
+
+    // uint8 tag 的高位不为 0
+    let mut bad = good.clone();
+    bad[62] = 1;
+    assert_error(Message::decode(&bad), ErrorCode::InvalidPayload);
+
+    // amount 超出 u128
+    let mut bad = good;
+    bad[5 * 32 + 15] = 1;
+    assert_error(Message::decode(&bad), ErrorCode::InvalidPayload);
+
+    // bool 只能是 0 或 1
+    let mut bad = hex::decode(ACK_VECTOR).unwrap();
+    bad[127] = 2;
+    assert_error(Message::decode(&bad), ErrorCode::InvalidPayload);
+}
+
+#[test]
+fn evm_addresses_must_be_left_padded() {
+    let word = address(USDC);
+    assert_eq!(evm_word(&evm_address(&word).unwrap()), word);
+
+    let mut bad = word;
+    bad[0] = 1;
+    assert_error(evm_address(&bad), ErrorCode::InvalidEvmAddress);
+    assert_error(evm_address(&[0xffu8; 32]), ErrorCode::InvalidEvmAddress);
+}
@@ -0,0 +1,163 @@
+//! MintConfig：入金上下限、每日流出上限及其窗口重置、enabled 开关，以及 update_mint_config 的权限。
+
+mod common;
+
+use anchor_lang::error::ErrorCode as AnchorErrorCode;
+use anchor_lang::prelude::*;
+
+use common::*;
+use transfer_contract::state::mint_config::OUTFLOW_WINDOW_SECS;
+use transfer_contract::state::{ErrorCode, MintConfig, MintConfigParams};
+use transfer_contract::{TransferOut, UpdateMintConfig};
+
+const NOW: i64 = 1_700_000_000;
+
+fn params(f: &Fixture) -> MintConfigParams {
+    let m = &f.mint_config_account;
+    MintConfigParams {
+        enabled: true,
+        min_deposit: 100,
+        max_deposit: 1_000,
+        daily_outflow_cap: 1_000,
+        dst_tokens: m.dst_tokens.clone(),
+        protocol_fee_bps: m.protocol_fee_bps,
+        lp_fee_bps: m.lp_fee_bps,
+        refund_dust: m.refund_dust,
+    }
+}
+
+/// 执行 update_mint_config，成功时返回更新后的 MintConfig
+fn update_mint_config(
+    f: &Fixture,
+    signer: Pubkey,
+    mint_config: AccountInfo<'static>,
+    params: MintConfigParams,
+) -> Result<MintConfig> {
+    let infos = vec![f.config_info(), mint_config, signer_info(signer), f.mint_info()];
+    run_handler(&transfer_contract::ID, infos, &params.try_to_vec().unwrap(), |ctx| {
+        let Context { program_id, accounts, remaining_accounts, bumps, .. } = ctx;
+        let ctx = Context::<UpdateMintConfig>::new(program_id, &mut *accounts, remaining_accounts, bumps);
+        transfer_contract::transfer_contract::update_mint_config(ctx, params)?;
+        Ok((*accounts.mint_config).clone())
+    })
+}
+
+/// admin 执行 transfer_out；MintDisabled 在 Clock 与转账 CPI 之前返回
+fn transfer_out(f: &Fixture) -> Result<()> {
+    let infos = vec![
+        f.config_info(),
+        signer_info(f.admin),
+        unchecked_info(f.vault_authority),
+        f.vault_info(),
+        f.user_token_info(),
+        f.mint_info(),
+        f.mint_config_info(),
+        f.token_program_info(),
+    ];
+    run_handler(&transfer_contract::ID, infos, &500u64.to_le_bytes(), |ctx| {
+        let Context { program_id, accounts, remaining_accounts, bumps, .. } = ctx;
+        let ctx = Context::<TransferOut>::new(program_id, &mut *accounts, remaining_accounts, bumps);
+        transfer_contract::transfer_contract::transfer_out(ctx, 500)
+    })
+}
+
+#[test]
+fn deposit_must_be_within_min_and_max() {
+    let f = Fixture::new();
+    let mut config = f.mint_config_account.clone();
+    config.apply(params(&f)).unwrap();
+    config.check_deposit(100).unwrap();
+    config.check_deposit(1_000).unwrap();
+    assert_error(config.check_deposit(99), ErrorCode::DepositBelowMinimum);
+    assert_error(config.check_deposit(1_001), ErrorCode::DepositAboveMaximum);
+
+    // max_deposit = 0 表示不设上限
+    config.max_deposit = 0;
+    config.check_deposit(u64::MAX).unwrap();
+}
+
+#[test]
+fn min_deposit_above_max_is_rejected() {
+    let f = Fixture::new();
+    let mut config = f.mint_config_account.clone();
+    let invalid = MintConfigParams { min_deposit: 1_001, ..params(&f) };
+    assert_error(config.apply(invalid), ErrorCode::InvalidMintConfig);
+    // 无上限时 min_deposit 可以任意大
+    config.apply(MintConfigParams { min_deposit: 1_001, max_deposit: 0, ..params(&f) }).unwrap();
+}
+
+#[test]
+fn outflow_is_capped_per_window() {
+    let f = Fixture::new();
+    let mut config = f.mint_config_account.clone();
+    config.apply(params(&f)).unwrap();
+    config.record_outflow(600, NOW).unwrap();
+    config.record_outflow(400, NOW + 10).unwrap();
+    assert_eq!((config.outflow_window_start, config.outflow_in_window), (NOW, 1_000));
+
+    // 超出上限的流出被拒绝，且不计入窗口
+    assert_error(config.record_outflow(1, NOW + 20), ErrorCode::OutflowCapExceeded);
+    assert_error(config.record_outflow(1, NOW + OUTFLOW_WINDOW_SECS - 1), ErrorCode::OutflowCapExceeded);
+    assert_eq!(config.outflow_in_window, 1_000);
+    assert_error(config.record_outflow(u64::MAX, NOW + 30), ErrorCode::OutflowCapExceeded);
+}
+
+#[test]
+fn outflow_window_resets_after_a_day() {
+    let f = Fixture::new();
+    let mut config = f.mint_config_account.clone();
+    config.apply(params(&f)).unwrap();
+    config.record_outflow(1_000, NOW).unwrap();
+
+    // 窗口从第一笔流出开始计时，满 OUTFLOW_WINDOW_SECS 后重新计数
+    let next = NOW + OUTFLOW_WINDOW_SECS;
+    config.record_outflow(1_000, next).unwrap();
+    assert_eq!((config.outflow_window_start, config.outflow_in_window), (next, 1_000));
+    // 单笔超过上限时即使在新窗口也被拒绝
+    assert_error(config.record_outflow(1_001, next + OUTFLOW_WINDOW_SECS), ErrorCode::OutflowCapExceeded);
+}
+
+#[test]
+fn zero_outflow_cap_is_unlimited() {
+    let f = Fixture::new();
+    let mut config = f.mint_config_account.clone();
+    assert_eq!(config.daily_outflow_cap, 0);
+    config.record_outflow(u64::MAX, NOW).unwrap();
+    assert_error(config.record_outflow(1, NOW), ErrorCode::OutflowCapExceeded);
+}
+
+#[test]
+fn disabled_mint_rejects_payouts() {
+    let mut f = Fixture::new();
+    f.mint_config_account.enabled = false;
+    assert_error(transfer_out(&f), ErrorCode::MintDisabled);
+}
+
+#[test]
+fn admin_updates_the_mint_config() {
+    let f = Fixture::new();
+    let params = MintConfigParams { enabled: false, ..params(&f) };
+    let config = update_mint_config(&f, f.admin, f.mint_config_info(), params).unwrap();
+    assert!(!config.enabled);
+    assert_eq!((config.min_deposit, config.max_deposit, config.daily_outflow_cap), (100, 1_000, 1_000));
+    // mint、LP mint 与流出窗口不受影响
+    let before = &f.mint_config_account;
+    assert_eq!((config.mint, config.lp_mint, config.decimals), (before.mint, before.lp_mint, before.decimals));
+    assert_eq!((config.outflow_window_start, config.outflow_in_window), (0, 0));
+}
+
+#[test]
+fn update_mint_config_is_admin_only() {
+    let f = Fixture::new();
+    assert_error(update_mint_config(&f, f.user, f.mint_config_info(), params(&f)), ErrorCode::NotAuthorized);
+    assert_error(
+        update_mint_config(&f, f.admin, f.mint_config_info(), MintConfigParams { min_deposit: 1_001, ..params(&f) }),
+        ErrorCode::InvalidMintConfig,
+    );
+    // 其它 mint 的配置
+    let other = Fixture::new();
+    assert_error(
+        update_mint_config(&f, f.admin, other.mint_config_info(), params(&f)),
+        AnchorErrorCode::ConstraintSeeds,
+    );
+}
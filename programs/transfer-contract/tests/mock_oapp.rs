@@ -0,0 +1,190 @@
+//! deposit_from_user / quote_deposit 发给 OApp 的指令与 mock-oapp 桩程序之间的约定：
+//! discriminator、参数布局、账户顺序，以及桩程序对这些指令的处理。
+
+mod common;
+
+use anchor_lang::error::ErrorCode as AnchorErrorCode;
+use anchor_lang::prelude::*;
+use anchor_lang::{Discriminator, Bumps};
+
+use common::*;
+use mock_oapp::{Peer, Store, PEER_SEED, STORE_SEED};
+use transfer_contract::instructions::deposit_from_user::{
+    quote_send_instruction, relay_send_instruction, QuoteSendParams, RelaySendParams, QUOTE_SEND_DISCRIMINATOR,
+    RELAY_SEND_DISCRIMINATOR,
+};
+use transfer_contract::messaging::{self, Message, Payout};
+
+const REMOTE_PEER: [u8; 32] = [7u8; 32];
+
+fn store_pda() -> Pubkey {
+    Pubkey::find_program_address(&[STORE_SEED], &mock_oapp::ID).0
+}
+
+fn peer_pda(dst_eid: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PEER_SEED, store_pda().as_ref(), &dst_eid.to_be_bytes()], &mock_oapp::ID)
+}
+
+fn mock_account<T: AccountSerialize>(key: Pubkey, value: &T) -> AccountInfo<'static> {
+    let mut data = Vec::new();
+    value.try_serialize(&mut data).unwrap();
+    account_info(key, mock_oapp::ID, data, false, false, false)
+}
+
+fn store_info() -> AccountInfo<'static> {
+    let (key, bump) = Pubkey::find_program_address(&[STORE_SEED], &mock_oapp::ID);
+    mock_account(key, &Store { admin: Pubkey::new_unique(), native_fee: 5_000, lz_token_fee: 70, bump })
+}
+
+fn peer_info(dst_eid: u32) -> AccountInfo<'static> {
+    let (key, bump) = peer_pda(dst_eid);
+    mock_account(key, &Peer { address: REMOTE_PEER, bump })
+}
+
+fn payout() -> Vec<u8> {
+    Message::Payout(Payout { receipt_id: 3, token: DST_TOKEN, recipient: [2u8; 32], amount: 1_000 }).encode()
+}
+
+fn relay_send_params(message: &[u8]) -> RelaySendParams {
+    RelaySendParams {
+        dst_eid: DST_EID,
+        message: messaging::to_hex(message),
+        options: vec![0, 3],
+        native_fee: 5_000,
+        lz_token_fee: 0,
+    }
+}
+
+/// 桩程序的指令：账户约束中的 #[instruction(..)] 参数在 discriminator 之后
+fn run<T, R>(
+    infos: Vec<AccountInfo<'static>>,
+    ix_data: &[u8],
+    handler: impl FnOnce(Context<'_, '_, 'static, 'static, T>) -> Result<R>,
+) -> Result<R>
+where
+    T: Accounts<'static, <T as Bumps>::Bumps> + Bumps,
+    <T as Bumps>::Bumps: Default,
+{
+    run_handler(&mock_oapp::ID, infos, &ix_data[8..], handler)
+}
+
+#[test]
+fn discriminators_match_the_oapp_interface() {
+    assert_eq!(RELAY_SEND_DISCRIMINATOR, mock_oapp::instruction::RelaySend::DISCRIMINATOR);
+    assert_eq!(QUOTE_SEND_DISCRIMINATOR, mock_oapp::instruction::QuoteSend::DISCRIMINATOR);
+    // layerzero_oapp.json 中 quoteSend / relaySend 的 discriminator
+    assert_eq!(QUOTE_SEND_DISCRIMINATOR, [207, 0, 49, 214, 160, 211, 76, 211]);
+    assert_eq!(RELAY_SEND_DISCRIMINATOR, [152, 242, 87, 43, 84, 188, 143, 155]);
+}
+
+#[test]
+fn relay_send_carries_the_encoded_message() {
+    let message = payout();
+    let caller = Pubkey::new_unique();
+    let params = relay_send_params(&message);
+    let endpoint = Pubkey::new_unique();
+    let ix = relay_send_instruction(mock_oapp::ID, peer_pda(DST_EID).0, store_pda(), endpoint, caller, &params).unwrap();
+
+    assert_eq!(ix.program_id, mock_oapp::ID);
+    // 只有 caller（vault_authority）签名且只读；store 可写（手续费从这里扣除），其余只读
+    let keys: Vec<_> = ix.accounts.iter().map(|m| m.pubkey).collect();
+    assert_eq!(keys, [peer_pda(DST_EID).0, store_pda(), endpoint, caller]);
+    let writable: Vec<_> = ix.accounts.iter().map(|m| m.is_writable).collect();
+    assert_eq!(writable, [false, true, false, false]);
+    assert!(!ix.accounts[..3].iter().any(|m| m.is_signer) && ix.accounts[3].is_signer);
+
+    let decoded = mock_oapp::instruction::RelaySend::try_from_slice(&ix.data[8..]).unwrap().params;
+    assert_eq!((decoded.dst_eid, decoded.native_fee, decoded.options), (DST_EID, 5_000, vec![0, 3]));
+    let bytes = mock_oapp::decode_message(&decoded.message).unwrap();
+    assert_eq!(bytes, message);
+    assert!(matches!(Message::decode(&bytes).unwrap(), Message::Payout(p) if p.receipt_id == 3 && p.amount == 1_000));
+}
+
+#[test]
+fn mock_accepts_the_vault_relay_send() {
+    let message = payout();
+    let params = relay_send_params(&message);
+    let (endpoint, caller) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let ix = relay_send_instruction(mock_oapp::ID, peer_pda(DST_EID).0, store_pda(), endpoint, caller, &params).unwrap();
+    let params = mock_oapp::instruction::RelaySend::try_from_slice(&ix.data[8..]).unwrap().params;
+    // caller 以只读 signer 传入
+    let caller_info = || account_info(caller, Pubkey::default(), vec![], true, false, false);
+    let infos = |peer| vec![peer, store_info(), unchecked_info(endpoint), caller_info()];
+
+    let result = run(infos(peer_info(DST_EID)), &ix.data, |ctx| mock_oapp::mock_oapp::relay_send(ctx, params.clone()));
+    if let Err(e) = result {
+        panic!("relay_send rejected: {e:?}");
+    }
+    // 其它 dst_eid 的 Peer
+    assert_error(
+        run(infos(peer_info(DST_EID + 1)), &ix.data, |ctx| mock_oapp::mock_oapp::relay_send(ctx, params.clone())),
+        AnchorErrorCode::ConstraintSeeds,
+    );
+    // vault_authority 没有签名
+    let unsigned = vec![peer_info(DST_EID), store_info(), unchecked_info(endpoint), unchecked_info(caller)];
+    assert_error(
+        run(unsigned, &ix.data, |ctx| mock_oapp::mock_oapp::relay_send(ctx, params.clone())),
+        AnchorErrorCode::AccountNotSigner,
+    );
+}
+
+#[test]
+fn mock_relay_send_charges_the_native_fee_from_the_store() {
+    let params = relay_send_params(&payout());
+    let (endpoint, caller) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let ix = relay_send_instruction(mock_oapp::ID, peer_pda(DST_EID).0, store_pda(), endpoint, caller, &params).unwrap();
+    // relay_send 以可写传入 store
+    assert!(ix.accounts[1].pubkey == store_pda() && ix.accounts[1].is_writable);
+    let params = mock_oapp::instruction::RelaySend::try_from_slice(&ix.data[8..]).unwrap().params;
+    let relay_send = |store: AccountInfo<'static>, remaining: Vec<AccountInfo<'static>>| {
+        let mut infos = vec![
+            peer_info(DST_EID),
+            store,
+            unchecked_info(endpoint),
+            account_info(caller, Pubkey::default(), vec![], true, false, false),
+        ];
+        infos.extend(remaining);
+        run(infos, &ix.data, |ctx| mock_oapp::mock_oapp::relay_send(ctx, params.clone()))
+    };
+
+    // 手续费从 Store 转给第一个可写的 remaining account
+    let (store, receiver) = (store_info(), unchecked_info(Pubkey::new_unique()));
+    let writable_receiver = account_info(Pubkey::new_unique(), Pubkey::default(), vec![], false, true, false);
+    relay_send(store.clone(), vec![receiver.clone(), writable_receiver.clone()]).unwrap();
+    assert_eq!(store.lamports(), LAMPORTS - 5_000);
+    assert_eq!((receiver.lamports(), writable_receiver.lamports()), (LAMPORTS, LAMPORTS + 5_000));
+
+    // 没有可写的 remaining account 时不扣费
+    let store = store_info();
+    relay_send(store.clone(), vec![receiver]).unwrap();
+    assert_eq!(store.lamports(), LAMPORTS);
+
+    // Store 扣费后不能低于免租金额
+    let store = store_info();
+    let rent = Rent::default().minimum_balance(store.data_len());
+    **store.try_borrow_mut_lamports().unwrap() = rent + 4_999;
+    assert_error(relay_send(store, vec![writable_receiver]), mock_oapp::MockOAppError::InsufficientFee);
+}
+
+#[test]
+fn mock_quote_send_returns_store_fees() {
+    let endpoint = Pubkey::new_unique();
+    let quote_send = |receiver, pay_in_lz_token| {
+        let message = messaging::to_hex(&payout());
+        let params = QuoteSendParams { dst_eid: DST_EID, receiver, message, options: vec![], pay_in_lz_token };
+        let ix = quote_send_instruction(mock_oapp::ID, store_pda(), peer_pda(DST_EID).0, endpoint, &params).unwrap();
+        // store、peer、endpoint，均只读
+        let keys: Vec<_> = ix.accounts.iter().map(|m| m.pubkey).collect();
+        assert_eq!(keys, [store_pda(), peer_pda(DST_EID).0, endpoint]);
+        assert!(ix.accounts.iter().all(|m| !m.is_writable && !m.is_signer));
+        let decoded = mock_oapp::instruction::QuoteSend::try_from_slice(&ix.data[8..]).unwrap().params;
+        assert_eq!((decoded.dst_eid, decoded.receiver, decoded.pay_in_lz_token), (DST_EID, receiver, pay_in_lz_token));
+        let infos = vec![store_info(), peer_info(DST_EID), unchecked_info(endpoint)];
+        run(infos, &ix.data, |ctx| mock_oapp::mock_oapp::quote_send(ctx, decoded))
+    };
+    let fee = quote_send(REMOTE_PEER, false).unwrap();
+    assert_eq!((fee.native_fee, fee.lz_token_fee), (5_000, 0));
+    assert_eq!(quote_send(REMOTE_PEER, true).unwrap().lz_token_fee, 70);
+    // receiver 不是 Peer 中的远端地址
+    assert_error(quote_send([8u8; 32], false), mock_oapp::MockOAppError::ReceiverMismatch);
+}
@@ -0,0 +1,131 @@
+//! 向量来自 EVM 侧 OptionsBuilder：
+//! OptionsBuilder.newOptions().addExecutor...Option(..)
+
+mod common;
+
+use common::*;
+use transfer_contract::messaging::evm_word;
+use transfer_contract::options::{
+    decode_executor_options, validate_options, ExecutorOption, OptionsBuilder, MAX_OPTIONS_LEN,
+};
+use transfer_contract::state::{ErrorCode, ExecutorConfig};
+
+const MERCHANT: &str = "d8da6bf26964af9d7eed9e03e53415d37aa96045";
+
+/// addExecutorLzReceiveOption(200000, 0)
+const LZ_RECEIVE_VECTOR: &str = "00030100110100000000000000000000000000030d40";
+
+/// 在 LZ_RECEIVE_VECTOR 之后 addExecutorNativeDropOption(1e15, MERCHANT)
+const NATIVE_DROP_VECTOR: &str = concat!(
+    "010031",
+    "02",
+    "000000000000000000038d7ea4c68000",
+    "000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045",
+);
+
+/// 在 LZ_RECEIVE_VECTOR 之后 addExecutorLzComposeOption(0, 50000, 0)
+const COMPOSE_VECTOR: &str = "0100130300000000000000000000000000000000c350";
+
+fn merchant() -> [u8; 32] {
+    evm_word(&hex::decode(MERCHANT).unwrap().try_into().unwrap())
+}
+
+fn hex_options(parts: &[&str]) -> Vec<u8> {
+    hex::decode(parts.concat()).unwrap()
+}
+
+#[test]
+fn builder_matches_evm_options_builder() {
+    assert_eq!(OptionsBuilder::new().lz_receive(200_000, 0).build(), hex_options(&[LZ_RECEIVE_VECTOR]));
+    assert_eq!(
+        OptionsBuilder::new().lz_receive(200_000, 0).native_drop(1_000_000_000_000_000, merchant()).build(),
+        hex_options(&[LZ_RECEIVE_VECTOR, NATIVE_DROP_VECTOR])
+    );
+    assert_eq!(
+        OptionsBuilder::new().lz_receive(200_000, 0).lz_compose(0, 50_000, 0).build(),
+        hex_options(&[LZ_RECEIVE_VECTOR, COMPOSE_VECTOR])
+    );
+    // value 非 0 时 lzReceive 参数为 gas + value
+    assert_eq!(
+        OptionsBuilder::new().lz_receive(200_000, 10_000_000_000_000_000).build(),
+        hex_options(&["0003", "0100210100000000000000000000000000030d400000000000000000002386f26fc10000"])
+    );
+}
+
+#[test]
+fn executor_options_round_trip() {
+    let options = OptionsBuilder::new()
+        .lz_receive(200_000, 5)
+        .native_drop(7, merchant())
+        .lz_compose(1, 50_000, 0)
+        .ordered_execution()
+        .build();
+    assert_eq!(
+        decode_executor_options(&options).unwrap(),
+        vec![
+            ExecutorOption::LzReceive { gas: 200_000, value: 5 },
+            ExecutorOption::NativeDrop { amount: 7, receiver: merchant() },
+            ExecutorOption::LzCompose { index: 1, gas: 50_000, value: 0 },
+            ExecutorOption::OrderedExecution,
+        ]
+    );
+    validate_options(&options).unwrap();
+}
+
+#[test]
+fn dvn_options_are_skipped() {
+    // addDVNPreCrimeOption(0)：worker 2，size 2，dvn_idx 0，option_type 1
+    let options = hex_options(&[LZ_RECEIVE_VECTOR, "0200020001"]);
+    assert_eq!(decode_executor_options(&options).unwrap(), vec![ExecutorOption::LzReceive { gas: 200_000, value: 0 }]);
+    validate_options(&options).unwrap();
+}
+
+#[test]
+fn malformed_options_are_rejected() {
+    let lz_receive = hex_options(&[LZ_RECEIVE_VECTOR]);
+    // 空 options、旧版 type-1 options
+    assert_error(validate_options(&[]), ErrorCode::InvalidOptions);
+    let legacy = hex_options(&["0001", "0000000000000000000000000000000000000000000000000000000000030d40"]);
+    assert_error(validate_options(&legacy), ErrorCode::InvalidOptions);
+    // 截断
+    assert_error(validate_options(&lz_receive[..lz_receive.len() - 1]), ErrorCode::InvalidOptions);
+    assert_error(validate_options(&[0, 3, 1, 0]), ErrorCode::InvalidOptions);
+    // 未知 worker、未知 option_type、参数长度不符
+    assert_error(validate_options(&hex_options(&[LZ_RECEIVE_VECTOR, "03000101"])), ErrorCode::InvalidOptions);
+    assert_error(validate_options(&hex_options(&[LZ_RECEIVE_VECTOR, "01000109"])), ErrorCode::InvalidOptions);
+    assert_error(validate_options(&hex_options(&["0003", "01000201ff"])), ErrorCode::InvalidOptions);
+    // 超长
+    let mut options = lz_receive.clone();
+    while options.len() <= MAX_OPTIONS_LEN {
+        options.extend_from_slice(&hex::decode(COMPOSE_VECTOR).unwrap());
+    }
+    assert_error(validate_options(&options), ErrorCode::InvalidOptions);
+}
+
+#[test]
+fn options_without_lz_receive_gas_are_rejected() {
+    assert_error(validate_options(&OptionsBuilder::new().build()), ErrorCode::MissingLzReceiveGas);
+    assert_error(validate_options(&OptionsBuilder::new().lz_receive(0, 1).build()), ErrorCode::MissingLzReceiveGas);
+    assert_error(
+        validate_options(&OptionsBuilder::new().native_drop(1, merchant()).build()),
+        ErrorCode::MissingLzReceiveGas,
+    );
+}
+
+#[test]
+fn route_defaults_come_from_executor_config() {
+    let route = Fixture::new().route_account;
+    assert_eq!(route.executor.options(&merchant()), hex_options(&[LZ_RECEIVE_VECTOR]));
+
+    let executor = ExecutorConfig {
+        lz_receive_gas: 200_000,
+        lz_receive_value: 0,
+        native_drop: 1_000_000_000_000_000,
+        compose_gas: 50_000,
+        compose_value: 0,
+    };
+    // native drop 给该笔入金的商户
+    let options = executor.options(&merchant());
+    assert_eq!(options, hex_options(&[LZ_RECEIVE_VECTOR, NATIVE_DROP_VECTOR, COMPOSE_VECTOR]));
+    validate_options(&options).unwrap();
+}
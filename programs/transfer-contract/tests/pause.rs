@@ -0,0 +1,275 @@
+//! 暂停开关：guardian 只能暂停不能恢复；每个受暂停约束的指令在对应开关打开时返回 Paused；
+//! pause_mint 只影响该 mint。所有指令都在 LiteSVM（common::svm）上完整执行，通过 cargo test-sbf 运行。
+
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::{self, get_associated_token_address_with_program_id};
+use anchor_spl::token::spl_token;
+
+use common::e2e::*;
+use common::svm::{assert_failure, assert_success, TransactionResult};
+use common::*;
+use transfer_contract::instructions::lz_receive::LzReceiveParams;
+use transfer_contract::messaging::{Message, Payout};
+use transfer_contract::state::{
+    Config, ErrorCode, MintConfig, PaymentStatus, PAUSE_ALL, PAUSE_CROSS_CHAIN, PAUSE_DEPOSITS,
+    PAUSE_LP_WITHDRAWALS, PAUSE_PAYOUTS,
+};
+
+/// claim_refund 使用的失败回执（编号与 deposit_from_user 新建的回执错开）
+const FAILED_RECEIPT_ID: u64 = 9;
+
+fn ix(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction { program_id: transfer_contract::ID, accounts: accounts.to_account_metas(None), data: data.data() }
+}
+
+/// 带 guardian、LP 份额与一笔失败回执的金库
+fn setup() -> (Env, Pubkey) {
+    let mut env = Env::new(spl_token::ID);
+    let guardian = Pubkey::new_unique();
+    env.f.config_account.guardian = guardian;
+    let f = &env.f;
+    let receipt = f.receipt(FAILED_RECEIPT_ID, PaymentStatus::Failed);
+    let infos = [
+        f.config_info(),
+        f.lp_mint_info(),
+        f.user_lp_info(),
+        anchor_account_info(receipt_pda(&f.config, FAILED_RECEIPT_ID).0, &receipt),
+        signer_info(f.admin),
+        signer_info(guardian),
+    ];
+    env.svm.load(infos);
+    (env, guardian)
+}
+
+fn pause(env: &mut Env, authority: Pubkey, flags: u8) -> TransactionResult {
+    let accounts = transfer_contract::accounts::Pause { config: env.f.config, authority };
+    env.svm.process(&ix(accounts, transfer_contract::instruction::Pause { flags }))
+}
+
+fn unpause(env: &mut Env, admin: Pubkey, flags: u8) -> TransactionResult {
+    let accounts = transfer_contract::accounts::Unpause { config: env.f.config, admin };
+    env.svm.process(&ix(accounts, transfer_contract::instruction::Unpause { flags }))
+}
+
+fn pause_mint(env: &mut Env, authority: Pubkey, mint: Pubkey, flags: u8) -> TransactionResult {
+    let config = env.f.config;
+    let mint_config = mint_config_pda(&config, &mint).0;
+    let accounts = transfer_contract::accounts::PauseMint { config, mint_config, authority, mint };
+    env.svm.process(&ix(accounts, transfer_contract::instruction::PauseMint { flags }))
+}
+
+fn unpause_mint(env: &mut Env, admin: Pubkey, mint: Pubkey, flags: u8) -> TransactionResult {
+    let config = env.f.config;
+    let mint_config = mint_config_pda(&config, &mint).0;
+    let accounts = transfer_contract::accounts::UnpauseMint { config, mint_config, admin, mint };
+    env.svm.process(&ix(accounts, transfer_contract::instruction::UnpauseMint { flags }))
+}
+
+fn deposit_from_user(env: &mut Env) -> TransactionResult {
+    env.deposit(env.params(100_000))
+}
+
+fn lp_deposit(env: &mut Env) -> TransactionResult {
+    let f = &env.f;
+    let accounts = transfer_contract::accounts::LpDeposit {
+        config: f.config,
+        user: f.user,
+        user_source_token: env.user_token,
+        vault_authority: f.vault_authority,
+        vault_token_account: f.vault_token_account,
+        mint: f.mint,
+        mint_config: f.mint_config,
+        lp_mint: f.lp_mint,
+        user_lp_token: get_associated_token_address_with_program_id(&f.user, &f.lp_mint, &f.token_program),
+        token_program: f.token_program,
+        associated_token_program: associated_token::ID,
+        system_program: system_program::ID,
+    };
+    env.svm.process(&ix(accounts, transfer_contract::instruction::LpDeposit { amount: 1_000 }))
+}
+
+fn lp_withdraw(env: &mut Env) -> TransactionResult {
+    let f = &env.f;
+    let accounts = transfer_contract::accounts::LpWithdraw {
+        config: f.config,
+        user: f.user,
+        vault_authority: f.vault_authority,
+        vault_token_account: f.vault_token_account,
+        user_destination_token: env.user_token,
+        mint: f.mint,
+        mint_config: f.mint_config,
+        lp_mint: f.lp_mint,
+        user_lp_token: get_associated_token_address_with_program_id(&f.user, &f.lp_mint, &f.token_program),
+        token_program: f.token_program,
+    };
+    env.svm.process(&ix(accounts, transfer_contract::instruction::LpWithdraw { shares: 100 }))
+}
+
+fn transfer_out_of(env: &mut Env, mint: Pubkey) -> TransactionResult {
+    let f = &env.f;
+    let token_program = f.token_program;
+    let accounts = transfer_contract::accounts::TransferOut {
+        config: f.config,
+        authority: f.admin,
+        vault_authority: f.vault_authority,
+        vault_token_account: vault_ata(&f.vault_authority, &mint, &token_program),
+        recipient_token_account: get_associated_token_address_with_program_id(&f.admin, &mint, &token_program),
+        mint,
+        mint_config: mint_config_pda(&f.config, &mint).0,
+        token_program,
+    };
+    let recipient = f.token_account_info(accounts.recipient_token_account, mint, f.admin, 0);
+    env.svm.load([recipient]);
+    env.svm.process(&ix(accounts, transfer_contract::instruction::TransferOut { amount: 500 }))
+}
+
+fn transfer_out(env: &mut Env) -> TransactionResult {
+    let mint = env.f.mint;
+    transfer_out_of(env, mint)
+}
+
+fn claim_refund(env: &mut Env) -> TransactionResult {
+    let f = &env.f;
+    let accounts = transfer_contract::accounts::ClaimRefund {
+        config: f.config,
+        user: f.user,
+        receipt: receipt_pda(&f.config, FAILED_RECEIPT_ID).0,
+        vault_authority: f.vault_authority,
+        vault_token_account: f.vault_token_account,
+        user_token_account: env.user_token,
+        mint: f.mint,
+        mint_config: f.mint_config,
+        token_program: f.token_program,
+    };
+    env.svm.process(&ix(accounts, transfer_contract::instruction::ClaimRefund {}))
+}
+
+fn lz_receive(env: &mut Env) -> TransactionResult {
+    let recipient = env.recipient();
+    let payout = Payout { receipt_id: 7, token: env.f.mint.to_bytes(), recipient: recipient.to_bytes(), amount: 500 };
+    let params = LzReceiveParams {
+        src_eid: DST_EID,
+        sender: REMOTE_OAPP,
+        nonce: 1,
+        guid: [1; 32],
+        message: Message::Payout(payout).encode(),
+        extra_data: vec![],
+    };
+    env.deliver(env.lz_receive_ix(recipient, params))
+}
+
+#[test]
+fn guardian_can_pause_but_not_unpause() {
+    let (mut env, guardian) = setup();
+    let (admin, mint) = (env.f.admin, env.f.mint);
+
+    assert_success(pause(&mut env, guardian, PAUSE_DEPOSITS | PAUSE_PAYOUTS));
+    assert_eq!(env.svm.anchor_account::<Config>(&env.f.config).paused, PAUSE_DEPOSITS | PAUSE_PAYOUTS);
+    assert_failure(unpause(&mut env, guardian, PAUSE_DEPOSITS), ErrorCode::NotAuthorized);
+
+    assert_success(pause_mint(&mut env, guardian, mint, PAUSE_LP_WITHDRAWALS));
+    assert_eq!(env.svm.anchor_account::<MintConfig>(&env.f.mint_config).paused, PAUSE_LP_WITHDRAWALS);
+    assert_failure(unpause_mint(&mut env, guardian, mint, PAUSE_LP_WITHDRAWALS), ErrorCode::NotAuthorized);
+
+    // 只有 admin 能恢复，且只清除指定的位
+    assert_success(unpause(&mut env, admin, PAUSE_DEPOSITS));
+    assert_eq!(env.svm.anchor_account::<Config>(&env.f.config).paused, PAUSE_PAYOUTS);
+    assert_success(unpause_mint(&mut env, admin, mint, PAUSE_LP_WITHDRAWALS));
+    assert_eq!(env.svm.anchor_account::<MintConfig>(&env.f.mint_config).paused, 0);
+
+    // 其他账户不能暂停；开关位必须非零且在 PAUSE_ALL 之内
+    let stranger = Pubkey::new_unique();
+    env.svm.load([signer_info(stranger)]);
+    assert_failure(pause(&mut env, stranger, PAUSE_DEPOSITS), ErrorCode::NotAuthorized);
+    assert_failure(pause_mint(&mut env, stranger, mint, PAUSE_DEPOSITS), ErrorCode::NotAuthorized);
+    assert_failure(pause(&mut env, guardian, 0), ErrorCode::InvalidPauseFlags);
+    assert_failure(pause(&mut env, guardian, PAUSE_ALL + 1), ErrorCode::InvalidPauseFlags);
+}
+
+#[test]
+fn unset_guardian_cannot_pause() {
+    let (mut env, _) = setup();
+    env.f.config_account.guardian = Pubkey::default();
+    env.svm.load([env.f.config_info(), signer_info(Pubkey::default())]);
+    assert_failure(pause(&mut env, Pubkey::default(), PAUSE_DEPOSITS), ErrorCode::NotAuthorized);
+}
+
+#[test]
+fn paused_instructions_fail_with_paused() {
+    type Op = fn(&mut Env) -> TransactionResult;
+    let cases: [(&str, u8, Op); 6] = [
+        ("deposit_from_user", PAUSE_DEPOSITS | PAUSE_CROSS_CHAIN, deposit_from_user),
+        ("lp_deposit", PAUSE_DEPOSITS, lp_deposit),
+        ("lp_withdraw", PAUSE_LP_WITHDRAWALS, lp_withdraw),
+        ("transfer_out", PAUSE_PAYOUTS, transfer_out),
+        ("claim_refund", PAUSE_PAYOUTS, claim_refund),
+        ("lz_receive", PAUSE_PAYOUTS | PAUSE_CROSS_CHAIN, lz_receive),
+    ];
+    for (name, mask, op) in cases {
+        for flag in [PAUSE_DEPOSITS, PAUSE_PAYOUTS, PAUSE_LP_WITHDRAWALS, PAUSE_CROSS_CHAIN] {
+            // 全局开关与 mint 开关效果相同
+            for global in [true, false] {
+                let (mut env, guardian) = setup();
+                let mint = env.f.mint;
+                if global {
+                    assert_success(pause(&mut env, guardian, flag));
+                } else {
+                    assert_success(pause_mint(&mut env, guardian, mint, flag));
+                }
+                let result = op(&mut env);
+                if flag & mask != 0 {
+                    assert_failure(result, ErrorCode::Paused);
+                } else {
+                    assert!(result.is_ok(), "{name} should not be paused by {flag:#06b}: {:?}", result.err());
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn unpause_restores_instructions() {
+    let (mut env, guardian) = setup();
+    let admin = env.f.admin;
+    assert_success(pause(&mut env, guardian, PAUSE_ALL));
+    assert_failure(transfer_out(&mut env), ErrorCode::Paused);
+    assert_success(unpause(&mut env, admin, PAUSE_ALL));
+    assert_success(transfer_out(&mut env));
+    assert_eq!(env.svm.token_amount(&env.f.vault_token_account), VAULT_BALANCE - 500);
+}
+
+#[test]
+fn pause_mint_only_affects_that_mint() {
+    let (mut env, guardian) = setup();
+    let f = &env.f;
+    let other = Pubkey::new_unique();
+    let other_config = MintConfig {
+        mint: other,
+        lp_mint: lp_mint_pda(&f.config, &other).0,
+        bump: mint_config_pda(&f.config, &other).1,
+        ..f.mint_config_account.clone()
+    };
+    let infos = [
+        program_mint_info(f.token_program, other, None, 1_000_000_000),
+        anchor_account_info(mint_config_pda(&f.config, &other).0, &other_config),
+        f.token_account_info(vault_ata(&f.vault_authority, &other, &f.token_program), other, f.vault_authority, 1_000),
+    ];
+    env.svm.load(infos);
+    let mint = env.f.mint;
+
+    assert_success(pause_mint(&mut env, guardian, mint, PAUSE_PAYOUTS));
+    assert_failure(transfer_out(&mut env), ErrorCode::Paused);
+    assert_success(transfer_out_of(&mut env, other));
+
+    // 全局开关与另一个 mint 的开关都未被修改
+    assert_eq!(env.svm.anchor_account::<Config>(&env.f.config).paused, 0);
+    assert_eq!(env.svm.anchor_account::<MintConfig>(&mint_config_pda(&env.f.config, &other).0).paused, 0);
+    // 被暂停 mint 的其他操作不受影响
+    assert_success(lp_deposit(&mut env));
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token;
+
+use common::*;
+use transfer_contract::state::{ErrorCode, PaymentStatus};
+use transfer_contract::ClaimRefund;
+
+#[test]
+fn ack_resolves_pending_payment() {
+    let f = Fixture::new();
+    let mut receipt = f.receipt(0, PaymentStatus::Sent);
+    receipt.acknowledge(true).unwrap();
+    assert_eq!(receipt.status, PaymentStatus::Delivered);
+    // 已确认的付款不能再被 ack / 判定超时
+    assert_error(receipt.acknowledge(false), ErrorCode::PaymentNotPending);
+    assert_error(receipt.expire(i64::MAX, 0), ErrorCode::PaymentNotPending);
+
+    let mut receipt = f.receipt(1, PaymentStatus::Sent);
+    receipt.acknowledge(false).unwrap();
+    assert_eq!(receipt.status, PaymentStatus::Failed);
+}
+
+#[test]
+fn expiry_requires_timeout() {
+    let f = Fixture::new();
+    let mut receipt = f.receipt(0, PaymentStatus::Sent);
+    assert_error(receipt.expire(1_000 + 3_599, 3_600), ErrorCode::RefundTimeoutNotReached);
+    receipt.expire(1_000 + 3_600, 3_600).unwrap();
+    assert_eq!(receipt.status, PaymentStatus::Failed);
+}
+
+#[test]
+fn refund_returns_net_amount_minus_fee_once() {
+    let f = Fixture::new();
+    let mut receipt = f.receipt(0, PaymentStatus::Failed);
+    // 1% 退款手续费，向下取整
+    assert_eq!(receipt.refund(100).unwrap(), (981, 9));
+    assert_eq!(receipt.status, PaymentStatus::Refunded);
+    assert_error(receipt.refund(100), ErrorCode::PaymentNotFailed);
+
+    let mut receipt = f.receipt(1, PaymentStatus::Sent);
+    assert_error(receipt.refund(0), ErrorCode::PaymentNotFailed);
+}
+
+fn claim_refund(f: &Fixture, signer: Pubkey) -> Result<ClaimRefund<'static>> {
+    let receipt = f.receipt(0, PaymentStatus::Failed);
+    try_accounts(
+        vec![
+            f.config_info(),
+            signer_info(signer),
+            anchor_account_info(receipt_pda(&f.config, 0).0, &receipt),
+            unchecked_info(f.vault_authority),
+            f.vault_info(),
+            token_account_info(Pubkey::new_unique(), f.mint, signer, 0),
+            mint_info(f.mint),
+            f.mint_config_info(),
+            program_info(spl_token::ID),
+        ],
+        &[],
+    )
+}
+
+#[test]
+fn only_original_user_can_claim() {
+    let f = Fixture::new();
+    if let Err(e) = claim_refund(&f, f.user) {
+        panic!("original user rejected: {e:?}");
+    }
+    assert_error(claim_refund(&f, Pubkey::new_unique()), ErrorCode::NotAuthorized);
+}
@@ -0,0 +1,33 @@
+mod common;
+
+use common::*;
+use transfer_contract::state::{ErrorCode, MAX_REMOTE_PEERS};
+
+const SRC_EID: u32 = 30101;
+const SENDER: [u8; 32] = [7u8; 32];
+
+#[test]
+fn only_registered_peer_is_trusted() {
+    let mut config = Fixture::new().config_account;
+    assert_error(config.require_remote_peer(SRC_EID, &SENDER), ErrorCode::UntrustedPeer);
+
+    config.set_remote_peer(SRC_EID, SENDER).unwrap();
+    config.require_remote_peer(SRC_EID, &SENDER).unwrap();
+    assert_error(config.require_remote_peer(SRC_EID, &[8u8; 32]), ErrorCode::UntrustedPeer);
+    assert_error(config.require_remote_peer(SRC_EID + 1, &SENDER), ErrorCode::UntrustedPeer);
+
+    // 同一源链覆盖，全 0 移除
+    config.set_remote_peer(SRC_EID, [8u8; 32]).unwrap();
+    assert_error(config.require_remote_peer(SRC_EID, &SENDER), ErrorCode::UntrustedPeer);
+    config.set_remote_peer(SRC_EID, [0u8; 32]).unwrap();
+    assert!(config.remote_peers.is_empty());
+}
+
+#[test]
+fn remote_peers_are_capped() {
+    let mut config = Fixture::new().config_account;
+    for eid in 0..MAX_REMOTE_PEERS as u32 {
+        config.set_remote_peer(eid, SENDER).unwrap();
+    }
+    assert_error(config.set_remote_peer(MAX_REMOTE_PEERS as u32, SENDER), ErrorCode::TooManyPeers);
+}
@@ -0,0 +1,66 @@
+mod common;
+
+use anchor_lang::prelude::*;
+
+use common::*;
+use transfer_contract::state::{ErrorCode, RouteParams, RouteToken};
+
+const MERCHANT: [u8; 32] = [2u8; 32];
+
+fn params(f: &Fixture) -> RouteParams {
+    RouteParams {
+        enabled: true,
+        min_amount: 100,
+        max_amount: 1_000,
+        executor: f.route_account.executor,
+        tokens: vec![RouteToken { mint: f.mint, token: DST_TOKEN }],
+        merchants: vec![MERCHANT],
+    }
+}
+
+#[test]
+fn matching_deposit_passes() {
+    let f = Fixture::new();
+    let mut route = f.route_account.clone();
+    route.apply(params(&f)).unwrap();
+    route.check_deposit(&f.mint, &DST_TOKEN, &MERCHANT, 100).unwrap();
+    route.check_deposit(&f.mint, &DST_TOKEN, &MERCHANT, 1_000).unwrap();
+}
+
+#[test]
+fn non_matching_deposit_is_rejected() {
+    let f = Fixture::new();
+    let mut route = f.route_account.clone();
+    route.apply(params(&f)).unwrap();
+
+    assert_error(route.check_deposit(&Pubkey::new_unique(), &DST_TOKEN, &MERCHANT, 100), ErrorCode::DstTokenNotAllowed);
+    assert_error(route.check_deposit(&f.mint, &[3u8; 32], &MERCHANT, 100), ErrorCode::DstTokenNotAllowed);
+    assert_error(route.check_deposit(&f.mint, &DST_TOKEN, &[3u8; 32], 100), ErrorCode::MerchantNotAllowed);
+    assert_error(route.check_deposit(&f.mint, &DST_TOKEN, &MERCHANT, 99), ErrorCode::DepositBelowMinimum);
+    assert_error(route.check_deposit(&f.mint, &DST_TOKEN, &MERCHANT, 1_001), ErrorCode::DepositAboveMaximum);
+
+    route.enabled = false;
+    assert_error(route.check_deposit(&f.mint, &DST_TOKEN, &MERCHANT, 100), ErrorCode::RouteDisabled);
+}
+
+#[test]
+fn empty_merchant_list_allows_any_merchant() {
+    let f = Fixture::new();
+    let route = f.route_account.clone();
+    route.check_deposit(&f.mint, &DST_TOKEN, &[3u8; 32], 1).unwrap();
+}
+
+#[test]
+fn invalid_route_config_is_rejected() {
+    let f = Fixture::new();
+    let mut route = f.route_account.clone();
+
+    let mut bad = params(&f);
+    bad.min_amount = 2_000;
+    assert_error(route.apply(bad), ErrorCode::InvalidRouteConfig);
+
+    // 没有 lzReceive gas 的默认 options 无法在目标链执行
+    let mut bad = params(&f);
+    bad.executor.lz_receive_gas = 0;
+    assert_error(route.apply(bad), ErrorCode::InvalidRouteConfig);
+}
@@ -0,0 +1,102 @@
+//! Token-2022 mint 扩展：转账手续费计算与不支持扩展的拒绝
+
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022;
+use spl_token_2022::extension::default_account_state::DefaultAccountState;
+use spl_token_2022::extension::non_transferable::NonTransferable;
+use spl_token_2022::extension::permanent_delegate::PermanentDelegate;
+use spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use spl_token_2022::extension::transfer_hook::TransferHook;
+use spl_token_2022::extension::{BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut};
+use spl_token_2022::state::{AccountState, Mint};
+
+use common::*;
+use transfer_contract::state::token_ext::{require_supported_mint, transfer_fee};
+use transfer_contract::state::ErrorCode;
+
+/// 构造一个带指定扩展的 Token-2022 mint，`init` 负责填写各扩展的字段
+fn mint_with_extensions(
+    extensions: &[ExtensionType],
+    init: impl FnOnce(&mut StateWithExtensionsMut<Mint>),
+) -> AccountInfo<'static> {
+    let len = ExtensionType::try_calculate_account_len::<Mint>(extensions).unwrap();
+    let mut data = vec![0u8; len];
+    let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+    state.base.decimals = DECIMALS;
+    state.base.is_initialized = true;
+    state.pack_base();
+    init(&mut state);
+    state.init_account_type().unwrap();
+    account_info(Pubkey::new_unique(), spl_token_2022::ID, data, false, false, false)
+}
+
+/// epoch 10 起从 1% 调整为 2%，单笔最多收 150
+fn transfer_fee_mint() -> AccountInfo<'static> {
+    mint_with_extensions(&[ExtensionType::TransferFeeConfig], |state| {
+        let config = state.init_extension::<TransferFeeConfig>(true).unwrap();
+        config.older_transfer_fee.epoch = 0.into();
+        config.older_transfer_fee.transfer_fee_basis_points = 100.into();
+        config.older_transfer_fee.maximum_fee = 150.into();
+        config.newer_transfer_fee.epoch = 10.into();
+        config.newer_transfer_fee.transfer_fee_basis_points = 200.into();
+        config.newer_transfer_fee.maximum_fee = 150.into();
+    })
+}
+
+#[test]
+fn transfer_fee_follows_epoch_and_cap() {
+    let mint = transfer_fee_mint();
+    assert_eq!(transfer_fee(&mint, 5_000, 0).unwrap(), 50);
+    assert_eq!(transfer_fee(&mint, 5_000, 10).unwrap(), 100);
+    // 手续费向上取整
+    assert_eq!(transfer_fee(&mint, 1, 0).unwrap(), 1);
+    // 超过 maximum_fee 时按上限收取
+    assert_eq!(transfer_fee(&mint, 1_000_000, 10).unwrap(), 150);
+    require_supported_mint(&mint).unwrap();
+}
+
+#[test]
+fn mints_without_transfer_fee_charge_nothing() {
+    assert_eq!(transfer_fee(&mint_info(Pubkey::new_unique()), 5_000, 0).unwrap(), 0);
+    let plain_2022 = program_mint_info(spl_token_2022::ID, Pubkey::new_unique(), None, 0);
+    assert_eq!(transfer_fee(&plain_2022, 5_000, 0).unwrap(), 0);
+    require_supported_mint(&plain_2022).unwrap();
+}
+
+#[test]
+fn unsupported_extensions_are_rejected() {
+    let non_transferable = mint_with_extensions(&[ExtensionType::NonTransferable], |state| {
+        state.init_extension::<NonTransferable>(true).unwrap();
+    });
+    assert_error(require_supported_mint(&non_transferable), ErrorCode::UnsupportedMintExtension);
+
+    let permanent_delegate = mint_with_extensions(&[ExtensionType::PermanentDelegate], |state| {
+        let delegate = state.init_extension::<PermanentDelegate>(true).unwrap();
+        delegate.delegate = Some(Pubkey::new_unique()).try_into().unwrap();
+    });
+    assert_error(require_supported_mint(&permanent_delegate), ErrorCode::UnsupportedMintExtension);
+}
+
+#[test]
+fn transfer_hook_mints_are_rejected() {
+    // 金库转账不附带钩子程序需要的额外账户
+    let transfer_hook = mint_with_extensions(&[ExtensionType::TransferHook], |state| {
+        let hook = state.init_extension::<TransferHook>(true).unwrap();
+        hook.program_id = Some(Pubkey::new_unique()).try_into().unwrap();
+    });
+    assert_error(require_supported_mint(&transfer_hook), ErrorCode::UnsupportedMintExtension);
+}
+
+#[test]
+fn default_frozen_mints_are_rejected() {
+    let default_state = |account_state: AccountState| {
+        mint_with_extensions(&[ExtensionType::DefaultAccountState], |state| {
+            state.init_extension::<DefaultAccountState>(true).unwrap().state = account_state as u8;
+        })
+    };
+    assert_error(require_supported_mint(&default_state(AccountState::Frozen)), ErrorCode::UnsupportedMintExtension);
+    // 默认状态为 Initialized 时新账户可以直接转账
+    require_supported_mint(&default_state(AccountState::Initialized)).unwrap();
+}
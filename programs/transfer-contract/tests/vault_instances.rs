@@ -0,0 +1,59 @@
+//! 同一程序下的多个金库：config 由 vault_id 区分，其余 PDA 都以 config 地址为前缀，
+//! 一个金库的账户不能拿到另一个金库的指令里使用。
+
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::error::ErrorCode as AnchorErrorCode;
+
+use common::*;
+use transfer_contract::TransferOut;
+
+fn transfer_out(config: &Fixture, accounts: &Fixture) -> Result<TransferOut<'static>> {
+    try_accounts(
+        vec![
+            config.config_info(),
+            signer_info(config.admin),
+            unchecked_info(accounts.vault_authority),
+            accounts.vault_info(),
+            accounts.user_token_info(),
+            accounts.mint_info(),
+            accounts.mint_config_info(),
+            accounts.token_program_info(),
+        ],
+        &[],
+    )
+}
+
+#[test]
+fn vaults_have_disjoint_pdas() {
+    let a = Fixture::with_vault_id(1);
+    let b = Fixture::with_vault_id(2);
+    assert_ne!(a.config, b.config);
+    assert_ne!(a.vault_authority, b.vault_authority);
+    // 同一个 mint 在两个金库下各有一套配置与 LP mint
+    let (b_mint_config, _) = mint_config_pda(&b.config, &a.mint);
+    let (b_lp_mint, _) = lp_mint_pda(&b.config, &a.mint);
+    assert_ne!(a.mint_config, b_mint_config);
+    assert_ne!(a.lp_mint, b_lp_mint);
+    assert_ne!(a.route, b.route);
+    assert_ne!(receipt_pda(&a.config, 0).0, receipt_pda(&b.config, 0).0);
+}
+
+#[test]
+fn accounts_cannot_cross_vaults() {
+    let a = Fixture::with_vault_id(1);
+    let b = Fixture::with_vault_id(2);
+    if let Err(e) = transfer_out(&a, &a) {
+        panic!("own accounts rejected: {e:?}");
+    }
+    // 金库 b 的 config 搭配金库 a 的 vault_authority / mint_config
+    assert_error(transfer_out(&b, &a), AnchorErrorCode::ConstraintSeeds);
+}
+
+#[test]
+fn config_address_must_match_vault_id() {
+    let mut f = Fixture::with_vault_id(1);
+    f.config_account.vault_id = 2;
+    assert_error(transfer_out(&f, &f), AnchorErrorCode::ConstraintSeeds);
+}
@@ -0,0 +1,170 @@
+//! 每个动用金库的指令都必须拒绝“伪造”的 vault_token_account：
+//! - owner 不是 vault_authority 的代币账户（例如用户自己的账户）；
+//! - owner 是 vault_authority 但不是规范 ATA 的代币账户。
+//!
+//! SPL Token 与 Token-2022 两套程序各跑一遍。deposit_from_user 在 LiteSVM 上执行，需 test-sbf feature。
+
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::error::ErrorCode as AnchorErrorCode;
+use anchor_lang::system_program;
+use anchor_spl::associated_token;
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022;
+
+#[cfg(feature = "test-sbf")]
+use common::e2e::Env;
+#[cfg(feature = "test-sbf")]
+use common::svm::{assert_failure, assert_success, TransactionResult};
+use common::*;
+use transfer_contract::{LpDeposit, LpWithdraw, TransferOut};
+
+const TOKEN_PROGRAMS: [Pubkey; 2] = [spl_token::ID, spl_token_2022::ID];
+
+enum Vault {
+    Canonical,
+    OwnedByUser,
+    NonCanonical,
+}
+
+fn vault(f: &Fixture, kind: Vault) -> AccountInfo<'static> {
+    match kind {
+        Vault::Canonical => f.vault_info(),
+        // 用户自己的代币账户冒充金库
+        Vault::OwnedByUser => f.token_account_info(Pubkey::new_unique(), f.mint, f.user, 0),
+        // owner 正确，但地址不是 vault_authority 的 ATA
+        Vault::NonCanonical => f.token_account_info(Pubkey::new_unique(), f.mint, f.vault_authority, 0),
+    }
+}
+
+// deposit_from_user 会先 init PaymentReceipt（system program CPI），约束校验要在 SVM 中执行整条指令才能走到 vault_token_account
+#[cfg(feature = "test-sbf")]
+fn deposit_from_user(token_program: Pubkey, kind: Vault) -> TransactionResult {
+    let mut env = Env::new(token_program);
+    let vault = vault(&env.f, kind);
+    let vault_token_account = *vault.key;
+    env.svm.load([vault]);
+    let accounts = transfer_contract::accounts::DepositFromUser { vault_token_account, ..env.deposit_accounts() };
+    env.deposit_with(accounts, env.params(100))
+}
+
+fn lp_deposit(token_program: Pubkey, kind: Vault) -> Result<LpDeposit<'static>> {
+    let f = Fixture::with_token_program(token_program);
+    try_accounts(
+        vec![
+            f.config_info(),
+            signer_info(f.user),
+            f.user_token_info(),
+            unchecked_info(f.vault_authority),
+            vault(&f, kind),
+            f.mint_info(),
+            f.mint_config_info(),
+            f.lp_mint_info(),
+            f.user_lp_info(),
+            f.token_program_info(),
+            program_info(associated_token::ID),
+            program_info(system_program::ID),
+        ],
+        &[],
+    )
+}
+
+fn lp_withdraw(token_program: Pubkey, kind: Vault) -> Result<LpWithdraw<'static>> {
+    let f = Fixture::with_token_program(token_program);
+    try_accounts(
+        vec![
+            f.config_info(),
+            signer_info(f.user),
+            unchecked_info(f.vault_authority),
+            vault(&f, kind),
+            f.user_token_info(),
+            f.mint_info(),
+            f.mint_config_info(),
+            f.lp_mint_info(),
+            f.user_lp_info(),
+            f.token_program_info(),
+        ],
+        &[],
+    )
+}
+
+fn transfer_out(token_program: Pubkey, kind: Vault) -> Result<TransferOut<'static>> {
+    let f = Fixture::with_token_program(token_program);
+    try_accounts(
+        vec![
+            f.config_info(),
+            signer_info(f.admin),
+            unchecked_info(f.vault_authority),
+            vault(&f, kind),
+            f.user_token_info(),
+            f.mint_info(),
+            f.mint_config_info(),
+            f.token_program_info(),
+        ],
+        &[],
+    )
+}
+
+fn assert_ok<T>(result: Result<T>) {
+    if let Err(e) = result {
+        panic!("canonical vault rejected: {e:?}");
+    }
+}
+
+#[cfg(feature = "test-sbf")]
+#[test]
+fn deposit_from_user_requires_canonical_vault() {
+    for program in TOKEN_PROGRAMS {
+        assert_success(deposit_from_user(program, Vault::Canonical));
+        assert_failure(deposit_from_user(program, Vault::OwnedByUser), AnchorErrorCode::ConstraintTokenOwner);
+        assert_failure(deposit_from_user(program, Vault::NonCanonical), AnchorErrorCode::ConstraintAssociated);
+    }
+}
+
+#[test]
+fn lp_deposit_requires_canonical_vault() {
+    for program in TOKEN_PROGRAMS {
+        assert_ok(lp_deposit(program, Vault::Canonical));
+        assert_error(lp_deposit(program, Vault::OwnedByUser), AnchorErrorCode::ConstraintTokenOwner);
+        assert_error(lp_deposit(program, Vault::NonCanonical), AnchorErrorCode::ConstraintAssociated);
+    }
+}
+
+#[test]
+fn lp_withdraw_requires_canonical_vault() {
+    for program in TOKEN_PROGRAMS {
+        assert_ok(lp_withdraw(program, Vault::Canonical));
+        assert_error(lp_withdraw(program, Vault::OwnedByUser), AnchorErrorCode::ConstraintTokenOwner);
+        assert_error(lp_withdraw(program, Vault::NonCanonical), AnchorErrorCode::ConstraintAssociated);
+    }
+}
+
+#[test]
+fn transfer_out_requires_canonical_vault() {
+    for program in TOKEN_PROGRAMS {
+        assert_ok(transfer_out(program, Vault::Canonical));
+        assert_error(transfer_out(program, Vault::OwnedByUser), AnchorErrorCode::ConstraintTokenOwner);
+        assert_error(transfer_out(program, Vault::NonCanonical), AnchorErrorCode::ConstraintAssociated);
+    }
+}
+
+// Token-2022 的金库搭配 SPL Token 程序（或反之）时，金库的 owner program 与传入的 token_program 不符
+#[test]
+fn vault_must_belong_to_token_program() {
+    let f = Fixture::with_token_program(spl_token_2022::ID);
+    let result: Result<TransferOut<'static>> = try_accounts(
+        vec![
+            f.config_info(),
+            signer_info(f.admin),
+            unchecked_info(f.vault_authority),
+            f.vault_info(),
+            f.user_token_info(),
+            f.mint_info(),
+            f.mint_config_info(),
+            program_info(spl_token::ID),
+        ],
+        &[],
+    );
+    assert_error(result, AnchorErrorCode::ConstraintAssociatedTokenTokenProgram);
+}
@@ -7,7 +7,7 @@ Usage:
     --mint <MINT_PUBKEY> \
     --amount <u64> \
     [--user <USER_PUBKEY>] \
-    [--rpc <RPC_URL>] [--payer <KEYPAIR_PATH>] [--create-atas]
+    [--rpc <RPC_URL>] [--payer <KEYPAIR_PATH>] [--vault-id <VAULT_ID>] [--create-atas]
 */
 
 import fs from 'fs';
@@ -36,6 +36,7 @@ type Args = {
   rpc?: string;
   payer?: string;
   createAtas?: boolean;
+  vaultId?: string;
 };
 
 function parseArgs(): Args {
@@ -45,6 +46,7 @@ function parseArgs(): Args {
     const a = argv[i];
     const v = argv[i + 1];
     if (a === '--program') args.program = v;
+    if (a === '--vault-id') args.vaultId = v;
     if (a === '--mint') args.mint = v;
     if (a === '--amount') args.amount = v;
     if (a === '--user') args.user = v;
@@ -90,7 +92,9 @@ async function main() {
 
   const CONFIG_SEED = Buffer.from('config');
   const VAULT_SEED = Buffer.from('vault');
-  const [configPda] = PublicKey.findProgramAddressSync([CONFIG_SEED], idlProgramId);
+  // 每个金库的 config 由 vault_id 区分（u64 大端）
+  const vaultIdBe = new anchor.BN(args.vaultId ?? '0').toArrayLike(Buffer, 'be', 8);
+  const [configPda] = PublicKey.findProgramAddressSync([CONFIG_SEED, vaultIdBe], idlProgramId);
   const [vaultAuthority] = PublicKey.findProgramAddressSync([VAULT_SEED, configPda.toBuffer()], idlProgramId);
 
   const mintInfo = await connection.getAccountInfo(mint);
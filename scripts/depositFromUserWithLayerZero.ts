@@ -12,7 +12,7 @@ Usage:
     --native-fee <LAMPORTS> \
     [--lz-token-fee <u64>] \
     [--user <USER_KEYPAIR>] \
-    [--rpc <RPC_URL>] [--payer <KEYPAIR_PATH>]
+    [--rpc <RPC_URL>] [--payer <KEYPAIR_PATH>] [--vault-id <VAULT_ID>]
 
 Example:
   ts-node scripts/depositFromUserWithLayerZero.ts \
@@ -52,6 +52,7 @@ type Args = {
   user?: string;
   rpc?: string;
   payer?: string;
+  vaultId?: string;
 };
 
 function parseArgs(): Args {
@@ -61,6 +62,7 @@ function parseArgs(): Args {
     const a = argv[i];
     const v = argv[i + 1];
     if (a === '--program') args.program = v;
+    if (a === '--vault-id') args.vaultId = v;
     if (a === '--mint') args.mint = v;
     if (a === '--amount') args.amount = v;
     if (a === '--dst-eid') args.dstEid = v;
@@ -146,7 +148,9 @@ async function main() {
 
   const CONFIG_SEED = Buffer.from('config');
   const VAULT_SEED = Buffer.from('vault');
-  const [configPda] = PublicKey.findProgramAddressSync([CONFIG_SEED], idlProgramId);
+  // 每个金库的 config 由 vault_id 区分（u64 大端）
+  const vaultIdBe = new anchor.BN(args.vaultId ?? '0').toArrayLike(Buffer, 'be', 8);
+  const [configPda] = PublicKey.findProgramAddressSync([CONFIG_SEED, vaultIdBe], idlProgramId);
   const [vaultAuthority] = PublicKey.findProgramAddressSync([VAULT_SEED, configPda.toBuffer()], idlProgramId);
 
   const mintInfo = await connection.getAccountInfo(mint);
@@ -155,6 +159,17 @@ async function main() {
 
   const userAta = getAssociatedTokenAddressSync(mint, user.publicKey, false, tokenProgram);
   const vaultAta = getAssociatedTokenAddressSync(mint, vaultAuthority, true, tokenProgram);
+  // 目标链路由：seeds = ["route", dst_eid (u32 BE)]
+  const dstEidBe = Buffer.alloc(4);
+  dstEidBe.writeUInt32BE(dstEid);
+  const [routePda] = PublicKey.findProgramAddressSync([Buffer.from('route'), configPda.toBuffer(), dstEidBe], idlProgramId);
+  // 协议手续费转入 config.treasury 的 ATA（需事先创建）
+  const configAccount: any = await (program.account as any).config.fetch(configPda);
+  const treasuryAta = getAssociatedTokenAddressSync(mint, configAccount.treasury, true, tokenProgram);
+  // 本笔入金的回执：seeds = ["receipt", config.next_receipt_id (u64 BE)]
+  const receiptIdBe = Buffer.alloc(8);
+  receiptIdBe.writeBigUInt64BE(BigInt(configAccount.nextReceiptId.toString()));
+  const [receiptPda] = PublicKey.findProgramAddressSync([Buffer.from('receipt'), configPda.toBuffer(), receiptIdBe], idlProgramId);
 
   // LayerZero OApp 相关账户
   const LAYERZERO_OAPP_PROGRAM_ID = new PublicKey('CV1qjq8phMMpxv62TExA9PpvTyZx58TNCqkFB2QQgJXH');
@@ -228,9 +243,9 @@ async function main() {
     dstEid,
     dstToken,
     merchant,
-    options: null, // 可选，默认为空
-    nativeFee: nativeFee > 0 ? new anchor.BN(nativeFee.toString()) : null,  // 可选，默认为 0
-    lzTokenFee: lzTokenFee > 0 ? new anchor.BN(lzTokenFee.toString()) : null, // 可选，默认为 0
+    options: null, // 可选，默认按路由的 executor 配置生成
+    nativeFee: nativeFee > 0 ? new anchor.BN(nativeFee.toString()) : null,  // 可选，默认使用链上报价
+    lzTokenFee: lzTokenFee > 0 ? new anchor.BN(lzTokenFee.toString()) : null, // 可选，传入表示用 LZ token 支付
   };
 
   console.log('调用 deposit_from_user with LayerZero...');
@@ -257,10 +272,13 @@ async function main() {
       .accounts({
         config: configPda,
         user: user.publicKey,
+        receipt: receiptPda,
         userSourceToken: userAta,
         vaultAuthority,
         vaultTokenAccount: vaultAta,
+        treasuryTokenAccount: treasuryAta,
         mint,
+        route: routePda,
         tokenProgram,
         // LayerZero 相关账户（只需 3 个：oapp_program, peer, store）
         layerzeroOappProgram: LAYERZERO_OAPP_PROGRAM_ID,
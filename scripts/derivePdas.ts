@@ -2,7 +2,7 @@
 Print config PDA and vault_authority PDA for the transfer_contract program.
 
 Usage:
-  ts-node scripts/derivePdas.ts [--program <PROGRAM_ID>] [--json]
+  ts-node scripts/derivePdas.ts [--program <PROGRAM_ID>] [--vault-id <VAULT_ID>] [--json]
 
 If --program is omitted, uses IDL.address from target/idl/transfer_contract.json.
 */
@@ -10,8 +10,9 @@ If --program is omitted, uses IDL.address from target/idl/transfer_contract.json
 import fs from 'fs';
 import path from 'path';
 import { PublicKey } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
 
-type Args = { program?: string; json?: boolean };
+type Args = { program?: string; vaultId?: string; json?: boolean };
 
 function parseArgs(): Args {
   const argv = process.argv.slice(2);
@@ -20,6 +21,7 @@ function parseArgs(): Args {
     const a = argv[i];
     const v = argv[i + 1];
     if (a === '--program') args.program = v;
+    if (a === '--vault-id') args.vaultId = v;
     if (a === '--json' || a.startsWith('--json=')) args.json = true;
   }
   if (!args.json) {
@@ -37,7 +39,9 @@ function main() {
   const CONFIG_SEED = Buffer.from('config');
   const VAULT_SEED = Buffer.from('vault');
 
-  const [configPda] = PublicKey.findProgramAddressSync([CONFIG_SEED], programId);
+  // 每个金库的 config 由 vault_id 区分（u64 大端）
+  const vaultIdBe = new anchor.BN(args.vaultId ?? '0').toArrayLike(Buffer, 'be', 8);
+  const [configPda] = PublicKey.findProgramAddressSync([CONFIG_SEED, vaultIdBe], programId);
   const [vaultAuthority] = PublicKey.findProgramAddressSync([VAULT_SEED, configPda.toBuffer()], programId);
 
   if (args.json) {
@@ -1,7 +1,10 @@
 /*
 Initialize the vault Config on-chain.
 
-This calls the program's `initialize(allowed_caller_authority, [USDC,USDT,XUSD])`.
+This calls the program's `initialize(vault_id, allowed_caller_authority)` and then
+`add_allowed_mint` for every mint that does not have a MintConfig PDA yet.
+New mints start enabled with no deposit limits and no destination tokens;
+use `update_mint_config` to set the LayerZero dst_eid -> token mapping.
 
 Usage examples:
 
@@ -10,7 +13,7 @@ Usage examples:
     --allowed-caller <ALLOWED_CALLER_AUTHORITY_PUBKEY> \
     --usdc <USDC_MINT> --usdt <USDT_MINT> --xusd <XUSD_MINT>
 
-Or with a comma list (exactly 3):
+Or with a comma list (1-16 mints; more can be added later with add_allowed_mint):
 
   ts-node scripts/initVault.ts \
     --program <PROGRAM_ID> \
@@ -19,6 +22,7 @@ Or with a comma list (exactly 3):
 
 Optional:
   --rpc <RPC_URL>   --payer <PATH_TO_KEYPAIR_JSON>
+  --vault-id <VAULT_ID>   vault instance id (default 0)
 */
 
 import fs from 'fs';
@@ -46,6 +50,7 @@ type Args = {
   rpc?: string;
   payer?: string;
   sendAtas?: boolean;
+  vaultId?: string;
 };
 
 function parseArgs(): Args {
@@ -55,6 +60,7 @@ function parseArgs(): Args {
     const a = argv[i];
     const v = argv[i + 1];
     if (a === '--program') args.program = v;
+    if (a === '--vault-id') args.vaultId = v;
     if (a === '--allowed-caller') args.allowedCaller = v;
     if (a === '--mints') args.mints = v.split(',').map((s: string) => s.trim());
     if (a === '--usdc') args.usdc = v;
@@ -78,8 +84,8 @@ function parseArgs(): Args {
   if (args.xusd) list.push(args.xusd);
   if (args.mints && args.mints.length > 0) list.push(...args.mints);
   const unique = Array.from(new Set(list));
-  if (unique.length < 1 || unique.length > 3) {
-    throw new Error('Provide 1-3 mints via --usdc/--usdt/--xusd or --mints <M1>[,<M2>[,<M3>]]');
+  if (unique.length < 1 || unique.length > 16) {
+    throw new Error('Provide 1-16 mints via --usdc/--usdt/--xusd or --mints <M1>[,<M2>,...]');
   }
   return {
     program: args.program,
@@ -120,7 +126,9 @@ async function main() {
   const CONFIG_SEED = Buffer.from('config');
   const VAULT_SEED = Buffer.from('vault');
 
-  const [configPda] = PublicKey.findProgramAddressSync([CONFIG_SEED], idlProgramId);
+  // 每个金库的 config 由 vault_id 区分（u64 大端）
+  const vaultIdBe = new anchor.BN(args.vaultId ?? '0').toArrayLike(Buffer, 'be', 8);
+  const [configPda] = PublicKey.findProgramAddressSync([CONFIG_SEED, vaultIdBe], idlProgramId);
   const [vaultAuthority] = PublicKey.findProgramAddressSync([VAULT_SEED, configPda.toBuffer()], idlProgramId);
 
   console.log('Program ID:      ', program.programId.toBase58());
@@ -134,7 +142,7 @@ async function main() {
   const configInfo = await connection.getAccountInfo(configPda);
   if (!configInfo) {
     const txSig = await program.methods
-      .initialize(allowedCaller, mints)
+      .initialize(new anchor.BN(args.vaultId ?? '0'), allowedCaller)
       .accounts({
         config: configPda,
         vaultAuthority: vaultAuthority,
@@ -147,6 +155,39 @@ async function main() {
     console.log('Config already exists. Skipping initialize.');
   }
 
+  for (const mint of mints) {
+    const [mintConfig] = PublicKey.findProgramAddressSync([CONFIG_SEED, configPda.toBuffer(), mint.toBuffer()], idlProgramId);
+    if (await connection.getAccountInfo(mintConfig)) {
+      console.log(`[MintConfig] mint=${mint.toBase58()} exists, skipping`);
+      continue;
+    }
+    // LP mint 由底层 mint 所属的 token program 创建
+    const mintOwner = (await connection.getAccountInfo(mint))?.owner;
+    if (!mintOwner) throw new Error(`Mint not found: ${mint.toBase58()}`);
+    const mintTokenProgram = mintOwner.equals(TOKEN_2022_PROGRAM_ID) ? TOKEN_2022_PROGRAM_ID : TOKEN_PROGRAM_ID;
+    const sig = await program.methods
+      .addAllowedMint({
+        enabled: true,
+        minDeposit: new anchor.BN(0),
+        maxDeposit: new anchor.BN(0),
+        dailyOutflowCap: new anchor.BN(0),
+        dstTokens: [],
+        protocolFeeBps: 0,
+        lpFeeBps: 0,
+        refundDust: false,
+      })
+      .accounts({
+        config: configPda,
+        mintConfig,
+        admin: wallet.publicKey,
+        mint,
+        tokenProgram: mintTokenProgram,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    console.log(`[MintConfig] mint=${mint.toBase58()} added tx=${sig}`);
+  }
+
   // Optionally create ATAs for the provided mints
   const ixes: any[] = [];
   for (const mint of mints) {
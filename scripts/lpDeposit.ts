@@ -7,7 +7,7 @@ Usage:
     --mint <MINT_PUBKEY> \
     --amount <u64> \
     [--user <USER_PUBKEY>] \
-    [--rpc <RPC_URL>] [--payer <KEYPAIR_PATH>] [--create-atas]
+    [--rpc <RPC_URL>] [--payer <KEYPAIR_PATH>] [--vault-id <VAULT_ID>] [--create-atas]
 */
 
 import fs from 'fs';
@@ -24,6 +24,7 @@ import * as anchor from '@coral-xyz/anchor';
 import {
   TOKEN_PROGRAM_ID,
   TOKEN_2022_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
   getAssociatedTokenAddressSync,
   createAssociatedTokenAccountInstruction,
 } from '@solana/spl-token';
@@ -41,6 +42,7 @@ async function main() {
     const a = argv[i];
     const v = argv[i + 1];
     if (a === '--program') args.program = v;
+    if (a === '--vault-id') args.vaultId = v;
     if (a === '--mint') args.mint = v;
     if (a === '--amount') args.amount = v;
     if (a === '--user') args.user = v;
@@ -73,10 +75,13 @@ async function main() {
 
   const CONFIG_SEED = Buffer.from('config');
   const VAULT_SEED = Buffer.from('vault');
-  const LP_SEED = Buffer.from('lp');
-  const [configPda] = PublicKey.findProgramAddressSync([CONFIG_SEED], idlProgramId);
+  const LP_MINT_SEED = Buffer.from('lp_mint');
+  // 每个金库的 config 由 vault_id 区分（u64 大端）
+  const vaultIdBe = new anchor.BN(args.vaultId ?? '0').toArrayLike(Buffer, 'be', 8);
+  const [configPda] = PublicKey.findProgramAddressSync([CONFIG_SEED, vaultIdBe], idlProgramId);
   const [vaultAuthority] = PublicKey.findProgramAddressSync([VAULT_SEED, configPda.toBuffer()], idlProgramId);
-  const [lpPda] = PublicKey.findProgramAddressSync([LP_SEED, userPubkey.toBuffer(), mint.toBuffer()], idlProgramId);
+  const [mintConfig] = PublicKey.findProgramAddressSync([CONFIG_SEED, configPda.toBuffer(), mint.toBuffer()], idlProgramId);
+  const [lpMint] = PublicKey.findProgramAddressSync([LP_MINT_SEED, configPda.toBuffer(), mint.toBuffer()], idlProgramId);
 
   const mintInfo = await connection.getAccountInfo(mint);
   if (!mintInfo) throw new Error(`Mint not found: ${mint.toBase58()}`);
@@ -84,6 +89,8 @@ async function main() {
 
   const userAta = getAssociatedTokenAddressSync(mint, userPubkey, false, tokenProgram);
   const vaultAta = getAssociatedTokenAddressSync(mint, vaultAuthority, true, tokenProgram);
+  // LP 份额是标准 SPL Token，由程序按需创建用户的 LP ATA
+  const userLpAta = getAssociatedTokenAddressSync(lpMint, userPubkey, false, tokenProgram);
 
   const ixes: any[] = [];
   if (args.createAtas) {
@@ -102,12 +109,15 @@ async function main() {
     .accounts({
       config: configPda,
       user: userPubkey,
-      liquidityPosition: lpPda,
       userSourceToken: userAta,
       vaultAuthority,
       vaultTokenAccount: vaultAta,
       mint,
+      mintConfig,
+      lpMint,
+      userLpToken: userLpAta,
       tokenProgram,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
     })
     .rpc();
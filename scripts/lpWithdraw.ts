@@ -1,5 +1,6 @@
 /*
-LP withdraw: user withdraws liquidity back to own ATA for a mint.
+LP withdraw: user burns LP shares (`--amount` is in shares) and receives a
+pro-rata slice of the vault balance in their own ATA for the mint.
 
 Usage:
   ts-node scripts/lpWithdraw.ts \
@@ -7,7 +8,7 @@ Usage:
     --mint <MINT_PUBKEY> \
     --amount <u64> \
     [--user <USER_PUBKEY>] \
-    [--rpc <RPC_URL>] [--payer <KEYPAIR_PATH>] [--create-atas]
+    [--rpc <RPC_URL>] [--payer <KEYPAIR_PATH>] [--vault-id <VAULT_ID>] [--create-atas]
 */
 
 import fs from 'fs';
@@ -40,6 +41,7 @@ async function main() {
     const a = argv[i];
     const v = argv[i + 1];
     if (a === '--program') args.program = v;
+    if (a === '--vault-id') args.vaultId = v;
     if (a === '--mint') args.mint = v;
     if (a === '--amount') args.amount = v;
     if (a === '--user') args.user = v;
@@ -72,10 +74,13 @@ async function main() {
 
   const CONFIG_SEED = Buffer.from('config');
   const VAULT_SEED = Buffer.from('vault');
-  const LP_SEED = Buffer.from('lp');
-  const [configPda] = PublicKey.findProgramAddressSync([CONFIG_SEED], idlProgramId);
+  const LP_MINT_SEED = Buffer.from('lp_mint');
+  // 每个金库的 config 由 vault_id 区分（u64 大端）
+  const vaultIdBe = new anchor.BN(args.vaultId ?? '0').toArrayLike(Buffer, 'be', 8);
+  const [configPda] = PublicKey.findProgramAddressSync([CONFIG_SEED, vaultIdBe], idlProgramId);
   const [vaultAuthority] = PublicKey.findProgramAddressSync([VAULT_SEED, configPda.toBuffer()], idlProgramId);
-  const [lpPda] = PublicKey.findProgramAddressSync([LP_SEED, userPubkey.toBuffer(), mint.toBuffer()], idlProgramId);
+  const [mintConfig] = PublicKey.findProgramAddressSync([CONFIG_SEED, configPda.toBuffer(), mint.toBuffer()], idlProgramId);
+  const [lpMint] = PublicKey.findProgramAddressSync([LP_MINT_SEED, configPda.toBuffer(), mint.toBuffer()], idlProgramId);
 
   const mintInfo = await connection.getAccountInfo(mint);
   if (!mintInfo) throw new Error(`Mint not found: ${mint.toBase58()}`);
@@ -83,6 +88,7 @@ async function main() {
 
   const userAta = getAssociatedTokenAddressSync(mint, userPubkey, false, tokenProgram);
   const vaultAta = getAssociatedTokenAddressSync(mint, vaultAuthority, true, tokenProgram);
+  const userLpAta = getAssociatedTokenAddressSync(lpMint, userPubkey, false, tokenProgram);
 
   const ixes: any[] = [];
   if (args.createAtas) {
@@ -99,11 +105,13 @@ async function main() {
     .accounts({
       config: configPda,
       user: userPubkey,
-      liquidityPosition: lpPda,
       vaultAuthority,
       vaultTokenAccount: vaultAta,
       userDestinationToken: userAta,
       mint,
+      mintConfig,
+      lpMint,
+      userLpToken: userLpAta,
       tokenProgram,
     })
     .rpc();
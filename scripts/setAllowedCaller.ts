@@ -5,6 +5,7 @@ Usage:
   ts-node scripts/setAllowedCaller.ts \
     --program <PROGRAM_ID> \
     --new-allowed <NEW_ALLOWED_CALLER_PUBKEY> \
+    [--vault-id <VAULT_ID>] \
     [--rpc https://api.devnet.solana.com] \
     [--payer ~/my_solana_wallet.json]
 */
@@ -20,6 +21,7 @@ type Args = {
   newAllowed: string;
   rpc?: string;
   payer?: string;
+  vaultId?: string;
 };
 
 function parseArgs(): Args {
@@ -29,6 +31,7 @@ function parseArgs(): Args {
     const a = argv[i];
     const v = argv[i + 1];
     if (a === '--program') args.program = v;
+    if (a === '--vault-id') args.vaultId = v;
     if (a === '--new-allowed') args.newAllowed = v;
     if (a === '--rpc') args.rpc = v;
     if (a === '--payer') args.payer = v;
@@ -67,7 +70,9 @@ async function main() {
   const program = new anchor.Program(idl as anchor.Idl, provider as anchor.Provider);
 
   const CONFIG_SEED = Buffer.from('config');
-  const [configPda] = PublicKey.findProgramAddressSync([CONFIG_SEED], idlProgramId);
+  // 每个金库的 config 由 vault_id 区分（u64 大端）
+  const vaultIdBe = new anchor.BN(args.vaultId ?? '0').toArrayLike(Buffer, 'be', 8);
+  const [configPda] = PublicKey.findProgramAddressSync([CONFIG_SEED, vaultIdBe], idlProgramId);
   const newAllowed = new PublicKey(args.newAllowed);
 
   console.log('Program ID:     ', idlProgramId.toBase58());